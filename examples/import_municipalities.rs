@@ -0,0 +1,92 @@
+//! Regenerates `data/municipalities.csv` from the list of the current Italian municipalities
+//! published by ISTAT, "Elenco dei comuni italiani". The suppressed municipalities and the
+//! validity intervals already in the registry are kept, since the ISTAT list doesn't carry them.
+//!
+//! ```text
+//! cargo run --example import_municipalities -- Elenco-comuni-italiani.csv > municipalities.csv
+//! mv municipalities.csv data/municipalities.csv
+//! ```
+
+use std::{collections::BTreeMap, env, fs, process};
+
+const REGISTRY_DATA: &str = include_str!("../data/municipalities.csv");
+
+const CODE_COLUMN: &str = "Codice Catastale del comune";
+const NAME_COLUMN: &str = "Denominazione (Italiana e straniera)";
+const PROVINCE_COLUMN: &str = "Sigla automobilistica";
+const REGION_COLUMN: &str = "Denominazione Regione";
+
+fn main() {
+    let Some(path) = env::args().nth(1) else {
+        exit("usage: import_municipalities <Elenco-comuni-italiani.csv>");
+    };
+    let bytes = fs::read(&path).unwrap_or_else(|error| exit(&format!("{path}: {error}")));
+    // The list is published in Latin-1, while copies saved by a spreadsheet are often in UTF-8.
+    let istat_data = String::from_utf8(bytes)
+        .unwrap_or_else(|error| error.as_bytes().iter().map(|&byte| byte as char).collect());
+
+    let mut lines = istat_data.lines();
+    let header = fields(lines.next().unwrap_or_default());
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|column| *column == name)
+            .unwrap_or_else(|| exit(&format!("{path}: missing column {name}")))
+    };
+    let columns = [
+        column(CODE_COLUMN),
+        column(NAME_COLUMN),
+        column(PROVINCE_COLUMN),
+        column(REGION_COLUMN),
+    ];
+
+    let mut municipalities: BTreeMap<String, Vec<String>> = REGISTRY_DATA
+        .lines()
+        .skip(1)
+        .map(|line| line.split(';').map(str::to_string).collect::<Vec<_>>())
+        .filter(|row| row.len() == 6)
+        .map(|row| (row[0].clone(), row))
+        .collect();
+
+    for line in lines {
+        let fields = fields(line);
+        let Some(&[code, name, province, region]) = columns
+            .iter()
+            .map(|&column| fields.get(column).copied())
+            .collect::<Option<Vec<&str>>>()
+            .as_deref()
+        else {
+            continue;
+        };
+        if code.is_empty() {
+            continue;
+        }
+
+        let validity = municipalities
+            .get(code)
+            .map(|row| row[4..].to_vec())
+            .unwrap_or_else(|| vec![String::new(), String::new()]);
+        let row = [code, name, province, region]
+            .iter()
+            .map(|field| field.to_string())
+            .chain(validity)
+            .collect();
+        municipalities.insert(code.to_string(), row);
+    }
+
+    println!("code;name;province;region;valid_from;valid_to");
+    for row in municipalities.values() {
+        println!("{}", row.join(";"));
+    }
+}
+
+fn fields(line: &str) -> Vec<&str> {
+    line.split(';')
+        .map(|field| field.trim().trim_matches('"').trim())
+        .collect()
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(1)
+}
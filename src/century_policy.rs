@@ -0,0 +1,176 @@
+use chrono::{Datelike, NaiveDate};
+
+/// Oldest century taken into account when looking backward for a birth date.
+const MAX_CENTURIES_BACK: i32 = 4;
+
+/// Policy used to resolve the century of the two digits birth year stored in a codice fiscale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CenturyPolicy {
+    /// Two digits years lower or equal to the pivot are placed in the 2000s, the others in the 1900s.
+    Pivot(u32),
+    /// The birth date is the most recent one that is not after the reference date.
+    RelativeTo(NaiveDate),
+    /// The birth date is not after the reference date and implies an age lower or equal to `max_age`.
+    /// More than one date could satisfy the policy when `max_age` is greater than 99.
+    MaxAge { reference: NaiveDate, max_age: u32 },
+}
+
+impl CenturyPolicy {
+    /// Returns every birth date satisfying the policy, ordered from the oldest to the most recent.
+    /// Dates that do not exist in the calendar (e.g. February 29 of a non leap year) are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::century_policy::CenturyPolicy;
+    /// use chrono::NaiveDate;
+    ///
+    /// let policy = CenturyPolicy::MaxAge {
+    ///     reference: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+    ///     max_age: 110,
+    /// };
+    /// assert_eq!(
+    ///     policy.candidates(15, 6, 1),
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(1915, 6, 1).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2015, 6, 1).unwrap()
+    ///     ]
+    /// );
+    /// ```
+    pub fn candidates(&self, year: u32, month: u32, day: u32) -> Vec<NaiveDate> {
+        let year = (year % 100) as i32;
+
+        match self {
+            CenturyPolicy::Pivot(pivot) => {
+                let century = if year <= *pivot as i32 { 2000 } else { 1900 };
+                NaiveDate::from_ymd_opt(century + year, month, day)
+                    .into_iter()
+                    .collect()
+            }
+            CenturyPolicy::RelativeTo(reference) => past_dates(reference, year, month, day)
+                .into_iter()
+                .last()
+                .into_iter()
+                .collect(),
+            CenturyPolicy::MaxAge { reference, max_age } => past_dates(reference, year, month, day)
+                .into_iter()
+                .filter(|date| age(date, reference) <= *max_age)
+                .collect(),
+        }
+    }
+
+    /// Returns the most recent birth date satisfying the policy, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::century_policy::CenturyPolicy;
+    /// use chrono::NaiveDate;
+    ///
+    /// let policy = CenturyPolicy::Pivot(30);
+    /// assert_eq!(policy.resolve(31, 12, 1), NaiveDate::from_ymd_opt(1931, 12, 1));
+    /// assert_eq!(policy.resolve(30, 12, 1), NaiveDate::from_ymd_opt(2030, 12, 1));
+    /// ```
+    pub fn resolve(&self, year: u32, month: u32, day: u32) -> Option<NaiveDate> {
+        self.candidates(year, month, day).last().copied()
+    }
+}
+
+/// Returns the dates with the given two digits year that are not after the reference date,
+/// ordered from the oldest to the most recent.
+fn past_dates(reference: &NaiveDate, year: i32, month: u32, day: u32) -> Vec<NaiveDate> {
+    let reference_century = reference.year() - reference.year().rem_euclid(100);
+
+    (0..=MAX_CENTURIES_BACK)
+        .rev()
+        .filter_map(|centuries_back| {
+            NaiveDate::from_ymd_opt(reference_century - 100 * centuries_back + year, month, day)
+        })
+        .filter(|date| date <= reference)
+        .collect()
+}
+
+/// Returns the age in completed years on the reference date of someone born on the given date.
+pub(crate) fn age(birthdate: &NaiveDate, reference: &NaiveDate) -> u32 {
+    let mut age = reference.year() - birthdate.year();
+    if (reference.month(), reference.day()) < (birthdate.month(), birthdate.day()) {
+        age -= 1;
+    }
+
+    age.max(0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn pivot_policy() {
+        let policy = CenturyPolicy::Pivot(23);
+
+        assert_eq!(policy.candidates(23, 1, 7), vec![date(2023, 1, 7)]);
+        assert_eq!(policy.candidates(24, 1, 7), vec![date(1924, 1, 7)]);
+    }
+
+    #[test]
+    fn pivot_policy_invalid_date() {
+        assert_eq!(CenturyPolicy::Pivot(23).candidates(50, 2, 30), vec![]);
+    }
+
+    #[test]
+    fn relative_to_policy() {
+        let policy = CenturyPolicy::RelativeTo(date(2023, 6, 15));
+
+        assert_eq!(policy.resolve(23, 6, 15), Some(date(2023, 6, 15)));
+        assert_eq!(policy.resolve(23, 6, 16), Some(date(1923, 6, 16)));
+        assert_eq!(policy.resolve(80, 1, 1), Some(date(1980, 1, 1)));
+    }
+
+    #[test]
+    fn relative_to_policy_leap_year() {
+        let policy = CenturyPolicy::RelativeTo(date(2150, 1, 1));
+
+        assert_eq!(policy.resolve(0, 2, 29), Some(date(2000, 2, 29)));
+    }
+
+    #[test]
+    fn max_age_policy_single_candidate() {
+        let policy = CenturyPolicy::MaxAge {
+            reference: date(2023, 6, 15),
+            max_age: 99,
+        };
+
+        assert_eq!(policy.candidates(30, 1, 1), vec![date(1930, 1, 1)]);
+        assert_eq!(policy.candidates(23, 6, 16), vec![date(1923, 6, 16)]);
+        assert_eq!(policy.candidates(23, 6, 14), vec![date(2023, 6, 14)]);
+    }
+
+    #[test]
+    fn max_age_policy_ambiguous() {
+        let policy = CenturyPolicy::MaxAge {
+            reference: date(2023, 6, 15),
+            max_age: 120,
+        };
+
+        assert_eq!(
+            policy.candidates(10, 3, 1),
+            vec![date(1910, 3, 1), date(2010, 3, 1)]
+        );
+        assert_eq!(policy.resolve(10, 3, 1), Some(date(2010, 3, 1)));
+    }
+
+    #[test]
+    fn max_age_policy_no_candidates() {
+        let policy = CenturyPolicy::MaxAge {
+            reference: date(2023, 6, 15),
+            max_age: 18,
+        };
+
+        assert_eq!(policy.candidates(80, 1, 1), vec![]);
+        assert_eq!(policy.resolve(80, 1, 1), None);
+    }
+}
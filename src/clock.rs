@@ -0,0 +1,39 @@
+//! Source of the current date, used as reference by the checks depending on it.
+//! Use [`FixedClock`] to get reproducible results, e.g. in tests.
+
+use chrono::{Local, NaiveDate};
+
+pub trait Clock {
+    /// Returns the current date.
+    fn today(&self) -> NaiveDate;
+}
+
+/// Clock returning the current date of the system, in the local timezone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        Local::now().date_naive()
+    }
+}
+
+/// Clock always returning the same date.
+///
+/// # Examples
+///
+/// ```
+/// use codice_fiscale_rs::clock::{Clock, FixedClock};
+/// use chrono::NaiveDate;
+///
+/// let today = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// assert_eq!(FixedClock(today).today(), today);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub NaiveDate);
+
+impl Clock for FixedClock {
+    fn today(&self) -> NaiveDate {
+        self.0
+    }
+}
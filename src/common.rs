@@ -5,6 +5,9 @@ const ALPHABET: &[char] = &[
     'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
 ];
 
+/// Characters that are ignored in names and surnames, see Circolare 34/E.
+const NAME_SEPARATORS: &[char] = &['\'', '’', '`', ' ', '-'];
+
 const MONTH_CODES: &[char] = &[
     'A', // January
     'B', // Febraury
@@ -21,11 +24,11 @@ const MONTH_CODES: &[char] = &[
 ];
 
 pub fn is_vowel(letter: &char) -> bool {
-    VOWELS.contains(letter)
+    VOWELS.contains(&letter.to_ascii_uppercase())
 }
 
 pub fn is_consonant(letter: &char) -> bool {
-    !VOWELS.contains(letter)
+    !is_vowel(letter)
 }
 
 pub fn to_alphabet(index: usize) -> char {
@@ -36,6 +39,38 @@ pub fn to_month_codes(index: usize) -> char {
     MONTH_CODES[index]
 }
 
-pub fn is_month_code(letter: &char) -> bool {
-    MONTH_CODES.contains(letter)
+pub fn from_month_code(letter: &char) -> Option<u32> {
+    MONTH_CODES
+        .iter()
+        .position(|month_code| month_code == letter)
+        .map(|index| index as u32 + 1)
+}
+
+/// Maps a latin letter with diacritics to its base letter, e.g. `È` to `E` and `ü` to `u`.
+pub fn remove_diacritics(letter: char) -> char {
+    match letter {
+        'À' | 'Á' | 'Â' | 'Ä' => 'A',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'Ò' | 'Ó' | 'Ô' | 'Ö' => 'O',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        'à' | 'á' | 'â' | 'ä' => 'a',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ò' | 'ó' | 'ô' | 'ö' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        _ => letter,
+    }
+}
+
+/// Normalizes a name or surname following the Circolare 34/E of Agenzia delle Entrate:
+/// apostrophes, spaces and hyphens are removed and accented letters are mapped to their base letter.
+/// The result is uppercase, e.g. `D'Angelo` becomes `DANGELO` and `Nicolò` becomes `NICOLO`.
+pub fn normalize_name_or_surname(value: &str) -> String {
+    value
+        .chars()
+        .filter(|char| !NAME_SEPARATORS.contains(char))
+        .map(remove_diacritics)
+        .map(|char| char.to_ascii_uppercase())
+        .collect()
 }
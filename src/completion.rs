@@ -0,0 +1,162 @@
+//! Completion of codici fiscali with a few illegible characters, marked with a wildcard.
+//! Each unknown character is replaced by the characters allowed by its segment and the
+//! resulting codici fiscali are kept only if they pass the verification.
+
+use crate::{
+    common::to_month_codes,
+    control_code::ControlCode,
+    errors::CompletionError,
+    omocodes::OMOCODES_LETTERS,
+    segment::Segment,
+    verifier::{BIRTH_PLACE_PART, CONTROL_CHARACTER_PART},
+    CodiceFiscale,
+};
+
+/// Character marking an illegible character.
+const WILDCARD: char = '?';
+
+/// Maximum number of illegible characters, it bounds the candidates to a few hundreds.
+const MAX_UNKNOWN_CHARACTERS: usize = 2;
+
+/// Returns every valid codice fiscale matching the provided one, where each wildcard
+/// can be any character. The control character, when unknown, is computed.
+pub(crate) fn complete(raw_codice_fiscale: &str) -> Result<Vec<CodiceFiscale>, CompletionError> {
+    let characters: Vec<char> = raw_codice_fiscale.chars().collect();
+    if characters.len() != 16 {
+        return Err(CompletionError::InvalidLength(characters.len()));
+    }
+
+    let unknown_positions: Vec<usize> = characters
+        .iter()
+        .enumerate()
+        .filter(|(_, character)| **character == WILDCARD)
+        .map(|(position, _)| position)
+        .collect();
+    if unknown_positions.len() > MAX_UNKNOWN_CHARACTERS {
+        return Err(CompletionError::TooManyUnknownCharacters(
+            unknown_positions.len(),
+        ));
+    }
+
+    let mut candidates = vec![characters];
+    for position in unknown_positions
+        .into_iter()
+        .filter(|position| *position != CONTROL_CHARACTER_PART.start)
+    {
+        candidates = candidates
+            .into_iter()
+            .flat_map(|candidate| {
+                allowed_characters(position)
+                    .into_iter()
+                    .map(move |character| {
+                        let mut candidate = candidate.to_vec();
+                        candidate[position] = character;
+                        candidate
+                    })
+            })
+            .collect();
+    }
+
+    Ok(candidates
+        .into_iter()
+        .filter_map(|mut candidate| {
+            let control_character = CONTROL_CHARACTER_PART.start;
+            if candidate[control_character] == WILDCARD {
+                candidate[control_character] =
+                    ControlCode::compute(&candidate.iter().collect::<String>());
+            }
+            CodiceFiscale::new(&candidate.iter().collect::<String>()).ok()
+        })
+        .collect())
+}
+
+/// The characters that can appear at the provided position: letters for the name, surname
+/// and the first character of the birth place, month codes for the month and digits or
+/// omocode letters elsewhere.
+fn allowed_characters(position: usize) -> Vec<char> {
+    match Segment::at(position) {
+        Some(Segment::BirthMonth) => (0..12).map(to_month_codes).collect(),
+        Some(Segment::BirthYear | Segment::BirthDayAndGender) => digits_and_omocodes_letters(),
+        Some(Segment::BirthPlace) if position != BIRTH_PLACE_PART.start => {
+            digits_and_omocodes_letters()
+        }
+        _ => ('A'..='Z').collect(),
+    }
+}
+
+fn digits_and_omocodes_letters() -> Vec<char> {
+    ('0'..='9').chain(OMOCODES_LETTERS).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codici_fiscali(completions: Vec<CodiceFiscale>) -> Vec<String> {
+        completions
+            .iter()
+            .map(|codice_fiscale| codice_fiscale.get())
+            .collect()
+    }
+
+    #[test]
+    fn complete_without_wildcards() {
+        assert_eq!(
+            codici_fiscali(complete("RSSMRA80A01H501U").unwrap()),
+            vec!["RSSMRA80A01H501U"]
+        );
+        assert_eq!(complete("RSSMRA80A01H501A"), Ok(vec![]));
+    }
+
+    #[test]
+    fn complete_one_wildcard() {
+        let completions = codici_fiscali(complete("RSSMRA80A0?H501U").unwrap());
+
+        assert!(completions.contains(&"RSSMRA80A01H501U".to_string()));
+        assert!(completions
+            .iter()
+            .all(|completion| CodiceFiscale::new(completion).is_ok()));
+    }
+
+    #[test]
+    fn complete_control_character() {
+        assert_eq!(
+            codici_fiscali(complete("RSSMRA80A01H501?").unwrap()),
+            vec!["RSSMRA80A01H501U"]
+        );
+    }
+
+    #[test]
+    fn complete_two_wildcards() {
+        let completions = codici_fiscali(complete("RS?MRA80?01H501U").unwrap());
+
+        assert!(completions.contains(&"RSSMRA80A01H501U".to_string()));
+        assert!(completions
+            .iter()
+            .all(|completion| completion.starts_with("RS") && completion.ends_with("01H501U")));
+    }
+
+    #[test]
+    fn complete_too_many_wildcards() {
+        assert_eq!(
+            complete("RS?MRA80?01H?01U"),
+            Err(CompletionError::TooManyUnknownCharacters(3))
+        );
+    }
+
+    #[test]
+    fn complete_invalid_length() {
+        assert_eq!(
+            complete("RSSMRA80A0?H501"),
+            Err(CompletionError::InvalidLength(15))
+        );
+    }
+
+    #[test]
+    fn allowed_characters_by_segment() {
+        assert_eq!(allowed_characters(0).len(), 26);
+        assert_eq!(allowed_characters(8).len(), 12);
+        assert_eq!(allowed_characters(11).len(), 26);
+        assert_eq!(allowed_characters(12).len(), 20);
+    }
+}
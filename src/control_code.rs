@@ -16,8 +16,7 @@ impl ControlCode {
             }
         }
 
-        let index_alphabet: usize = (&control_code % 26).try_into().unwrap();
-        ALPHABET[index_alphabet]
+        ALPHABET[control_code.rem_euclid(26) as usize]
     }
 }
 
@@ -0,0 +1,187 @@
+//! Registry of the foreign countries and their codice catastale, the `Z` codes used as birth
+//! place for people born abroad, with the related ISO 3166-1 alpha-2 and alpha-3 codes.
+//!
+//! The registry is embedded in the crate from `data/countries.csv` and it's available only when
+//! the `registry` feature is enabled. States that no longer exist, like the Soviet Union or
+//! Yugoslavia, are marked as historical and carry the ISO 3166-1 codes they used to have.
+//! At the moment it contains a subset of the countries listed by Agenzia delle Entrate.
+
+use std::sync::OnceLock;
+
+const COUNTRIES_DATA: &str = include_str!("../data/countries.csv");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    code: &'static str,
+    name: &'static str,
+    alpha2: &'static str,
+    alpha3: &'static str,
+    historical: bool,
+}
+
+impl Country {
+    /// Returns the country identified by the provided codice catastale, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::countries::Country;
+    ///
+    /// let country = Country::from_code("Z404").unwrap();
+    /// assert_eq!(country.alpha2(), "US");
+    /// assert_eq!(country.alpha3(), "USA");
+    /// ```
+    pub fn from_code(code: &str) -> Option<&'static Country> {
+        Country::all()
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Returns the country with the provided ISO 3166-1 alpha-2 code, if it exists.
+    /// Some codes of historical states have been assigned again, in that case
+    /// the current state is preferred. `CS` has been used by two historical states,
+    /// Czechoslovakia and Serbia and Montenegro: it's resolved to Czechoslovakia, the
+    /// first one, use the unambiguous alpha-3 codes `CSK` and `SCG` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::countries::Country;
+    ///
+    /// assert_eq!(Country::from_alpha2("DE").unwrap().code(), "Z112");
+    /// assert_eq!(Country::from_alpha2("SU").unwrap().code(), "Z135");
+    /// ```
+    pub fn from_alpha2(alpha2: &str) -> Option<&'static Country> {
+        Country::all()
+            .iter()
+            .filter(|country| country.alpha2.eq_ignore_ascii_case(alpha2))
+            .min_by_key(|country| country.historical)
+    }
+
+    /// Returns the country with the provided ISO 3166-1 alpha-3 code, if it exists.
+    /// Some codes of historical states have been assigned again, in that case
+    /// the current state is preferred.
+    pub fn from_alpha3(alpha3: &str) -> Option<&'static Country> {
+        Country::all()
+            .iter()
+            .filter(|country| country.alpha3.eq_ignore_ascii_case(alpha3))
+            .min_by_key(|country| country.historical)
+    }
+
+    /// Returns the country with the provided ISO 3166-1 code, either alpha-2 or alpha-3.
+    pub fn from_iso(iso_code: &str) -> Option<&'static Country> {
+        match iso_code.len() {
+            2 => Country::from_alpha2(iso_code),
+            3 => Country::from_alpha3(iso_code),
+            _ => None,
+        }
+    }
+
+    /// Returns all the countries in the registry.
+    pub fn all() -> &'static [Country] {
+        static COUNTRIES: OnceLock<Vec<Country>> = OnceLock::new();
+        COUNTRIES.get_or_init(|| {
+            COUNTRIES_DATA
+                .lines()
+                .skip(1)
+                .filter_map(parse_country)
+                .collect()
+        })
+    }
+
+    /// The codice catastale of the country, e.g. `Z404` for the United States.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The Italian name of the country.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn alpha2(&self) -> &'static str {
+        self.alpha2
+    }
+
+    pub fn alpha3(&self) -> &'static str {
+        self.alpha3
+    }
+
+    /// Returns true if the country no longer exists.
+    pub fn is_historical(&self) -> bool {
+        self.historical
+    }
+}
+
+fn parse_country(line: &'static str) -> Option<Country> {
+    match line.split(';').collect::<Vec<&str>>().as_slice() {
+        &[code, name, alpha2, alpha3, historical] => Some(Country {
+            code,
+            name,
+            alpha2,
+            alpha3,
+            historical: historical.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_parse_all_lines() {
+        assert_eq!(Country::all().len(), COUNTRIES_DATA.lines().count() - 1);
+    }
+
+    #[test]
+    fn registry_codes_are_foreign() {
+        assert!(Country::all()
+            .iter()
+            .all(|country| country.code.starts_with('Z')));
+    }
+
+    #[test]
+    fn registry_from_code() {
+        assert_eq!(
+            Country::from_code("z112"),
+            Some(&Country {
+                code: "Z112",
+                name: "Germania",
+                alpha2: "DE",
+                alpha3: "DEU",
+                historical: false,
+            })
+        );
+    }
+
+    #[test]
+    fn registry_from_iso() {
+        assert_eq!(Country::from_iso("fr").unwrap().code(), "Z110");
+        assert_eq!(Country::from_iso("FRA").unwrap().code(), "Z110");
+        assert_eq!(Country::from_iso("FRAN"), None);
+    }
+
+    #[test]
+    fn registry_historical_states() {
+        assert!(Country::from_alpha3("YUG").unwrap().is_historical());
+        assert_eq!(Country::from_alpha3("CSK").unwrap().code(), "Z105");
+        assert_eq!(Country::from_alpha3("SCG").unwrap().code(), "Z157");
+        assert_eq!(Country::from_alpha2("CS").unwrap().code(), "Z105");
+    }
+
+    #[test]
+    fn registry_asian_country() {
+        let country = Country::from_code("Z209").unwrap();
+
+        assert_eq!(country.name(), "Sri Lanka");
+        assert_eq!(Country::from_iso("LKA"), Some(country));
+    }
+
+    #[test]
+    fn registry_unknown_country() {
+        assert_eq!(Country::from_code("Z999"), None);
+        assert_eq!(Country::from_alpha2("XX"), None);
+    }
+}
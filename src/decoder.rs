@@ -0,0 +1,109 @@
+use chrono::NaiveDate;
+
+use crate::{century_policy::CenturyPolicy, person_data::Gender};
+
+#[cfg(feature = "registry")]
+use crate::countries::Country;
+
+/// Structured view of the data encoded in a codice fiscale. All the
+/// omocode characters are mapped back to digits before being interpreted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedCodiceFiscale {
+    pub(crate) surname: String,
+    pub(crate) name: String,
+    pub(crate) birth_year: u32,
+    pub(crate) birth_month: u32,
+    pub(crate) birth_day: u32,
+    pub(crate) gender: Gender,
+    pub(crate) birth_place: String,
+    pub(crate) control_character: char,
+    pub(crate) omocode_positions: Vec<usize>,
+}
+
+impl DecodedCodiceFiscale {
+    /// The three letters encoding the surname.
+    pub fn surname(&self) -> String {
+        self.surname.to_string()
+    }
+
+    /// The three letters encoding the name.
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    /// The last two digits of the birth year, between 0 and 99.
+    pub fn birth_year(&self) -> u32 {
+        self.birth_year
+    }
+
+    /// The birth month, between 1 and 12.
+    pub fn birth_month(&self) -> u32 {
+        self.birth_month
+    }
+
+    /// The birth day, between 1 and 31, without the gender offset.
+    pub fn birth_day(&self) -> u32 {
+        self.birth_day
+    }
+
+    pub fn gender(&self) -> Gender {
+        self.gender
+    }
+
+    /// The codice catastale (Belfiore code) of the birth place.
+    pub fn birth_place(&self) -> String {
+        self.birth_place.to_string()
+    }
+
+    /// Returns the birth country when the birth place is the code of a foreign country
+    /// in the embedded registry, `None` for people born in Italy or unknown codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let decoded = CodiceFiscale::new("RSSMRA80A01Z404R").unwrap().decode().unwrap();
+    /// assert_eq!(decoded.birth_country().unwrap().alpha2(), "US");
+    /// ```
+    #[cfg(feature = "registry")]
+    pub fn birth_country(&self) -> Option<&'static Country> {
+        Country::from_code(&self.birth_place)
+    }
+
+    pub fn control_character(&self) -> char {
+        self.control_character
+    }
+
+    /// The positions, in ascending order, of the digits replaced by an omocode letter.
+    /// It's empty if the codice fiscale is not an omocode.
+    pub fn omocode_positions(&self) -> Vec<usize> {
+        self.omocode_positions.to_vec()
+    }
+
+    /// Returns the full birth date, resolving the century with the provided policy.
+    /// If the policy allows more than one century the most recent date is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::century_policy::CenturyPolicy;
+    /// use chrono::NaiveDate;
+    ///
+    /// let decoded = CodiceFiscale::new("BRNPRZ72D52F83VC").unwrap().decode().unwrap();
+    /// assert_eq!(
+    ///     decoded.birthdate(&CenturyPolicy::Pivot(30)),
+    ///     NaiveDate::from_ymd_opt(1972, 4, 12)
+    /// );
+    /// ```
+    pub fn birthdate(&self, policy: &CenturyPolicy) -> Option<NaiveDate> {
+        policy.resolve(self.birth_year, self.birth_month, self.birth_day)
+    }
+
+    /// Returns every full birth date allowed by the provided policy, ordered from the oldest
+    /// to the most recent. More than one date is returned when the century is ambiguous.
+    pub fn birthdate_candidates(&self, policy: &CenturyPolicy) -> Vec<NaiveDate> {
+        policy.candidates(self.birth_year, self.birth_month, self.birth_day)
+    }
+}
@@ -1,5 +1,10 @@
+use std::ops::Range;
+
+use chrono::NaiveDate;
 use thiserror::Error;
 
+use crate::{segment::Segment, verifier::BIRTH_DATE_PART};
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum PersonDataError {
     #[error("The provided name contains non ascii alphabetic characters")]
@@ -8,9 +13,11 @@ pub enum PersonDataError {
     InvalidSurname(),
     #[error("The provided birth place is not valid, it should be a valid belfiore code")]
     InvalidBirthPlace(),
+    #[error("The provided country is not a known ISO 3166-1 alpha-2 or alpha-3 code")]
+    InvalidCountry(),
 }
 
-#[derive(Error, Debug, PartialEq, Eq)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum VerifierError {
     #[error("The fiscal code length should be 16 instead is `{0}`")]
     InvalidLength(usize),
@@ -28,10 +35,159 @@ pub enum VerifierError {
     InvalidBirthDayAndGender(String),
     #[error("The fiscal code birth day and gender part should be a 2 digits number between 1-31 and 41-71 instead is `{0}`")]
     InvalidBirthDayAndGenderRange(u32),
+    #[error("The fiscal code birth date, day `{2}` of month `{1}` of year `{0:02}`, does not exist in the calendar")]
+    InvalidBirthDate(u32, u32, u32),
+    #[error("The fiscal code birth date, day `{2}` of month `{1}` of year `{0:02}`, is not in any century allowed by the policy")]
+    UnresolvedBirthDate(u32, u32, u32),
+    #[error("The fiscal code birth date `{0}` is after the current date `{1}`")]
+    FutureBirthDate(NaiveDate, NaiveDate),
+    #[error(
+        "The fiscal code birth date `{0}` implies an age of `{1}` years, over the maximum of `{2}`"
+    )]
+    ImplausibleAge(NaiveDate, u32, u32),
     #[error("The fiscal code birth place part should be a valid belfiore code instead is `{0}`")]
     InvalidBirthPlace(String),
+    #[error("The fiscal code birth place `{0}` is not a known municipality or country")]
+    UnknownBirthPlace(String),
+    #[error("The fiscal code birth place `{0}` did not exist on the birth date `{1}`")]
+    InactiveBirthPlace(String, NaiveDate),
     #[error("The fiscal code control character is invalid, found `{0}` expected `{1}`")]
     InvalidControlCharacter(char, char),
-    #[error("The fiscal code shoud not contains any non alphanumeric character, invalid character at position `{0}`")]
-    NonAlphanumericCharacter(usize),
+    #[error(
+        "The fiscal code character `{0}` at position `{1}` should be a digit or an omocode letter"
+    )]
+    InvalidOmocodeCharacter(char, usize),
+    #[error("The fiscal code shoud not contains any non alphanumeric character, invalid character `{0}` at position `{1}`")]
+    NonAlphanumericCharacter(char, usize),
+    #[error(
+        "The fiscal code should contain only uppercase letters, lowercase letter at position `{0}`"
+    )]
+    LowercaseCharacter(usize),
+}
+
+impl VerifierError {
+    /// Returns the segment of the codice fiscale the error refers to, `None` when it concerns
+    /// the whole codice fiscale or, as the birth date errors, more than one segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::segment::Segment;
+    ///
+    /// let error = CodiceFiscale::new("BLTMHL77X04E889G").unwrap_err();
+    /// assert_eq!(error.segment(), Some(Segment::BirthMonth));
+    /// ```
+    pub fn segment(&self) -> Option<Segment> {
+        match self {
+            VerifierError::InvalidLength(_)
+            | VerifierError::InvalidBirthDate(_, _, _)
+            | VerifierError::UnresolvedBirthDate(_, _, _)
+            | VerifierError::FutureBirthDate(_, _)
+            | VerifierError::ImplausibleAge(_, _, _) => None,
+            VerifierError::InvalidSurname(_) => Some(Segment::Surname),
+            VerifierError::InvalidName(_) => Some(Segment::Name),
+            VerifierError::InvalidBirthYear(_) => Some(Segment::BirthYear),
+            VerifierError::InvalidBirthMonth(_) => Some(Segment::BirthMonth),
+            VerifierError::InvalidBirthDayAndGender(_)
+            | VerifierError::InvalidBirthDayAndGenderRange(_) => Some(Segment::BirthDayAndGender),
+            VerifierError::InvalidBirthPlace(_)
+            | VerifierError::UnknownBirthPlace(_)
+            | VerifierError::InactiveBirthPlace(_, _) => Some(Segment::BirthPlace),
+            VerifierError::InvalidControlCharacter(_, _) => Some(Segment::ControlCharacter),
+            VerifierError::InvalidOmocodeCharacter(_, position)
+            | VerifierError::NonAlphanumericCharacter(_, position)
+            | VerifierError::LowercaseCharacter(position) => Segment::at(*position),
+        }
+    }
+
+    /// Returns the byte positions of the wrong characters within the codice fiscale: the single
+    /// character for omocode, non alphanumeric and lowercase errors, the year, month and day for
+    /// birth date errors, the whole segment otherwise. A span always covers whole characters,
+    /// so it can be used to slice the verified value.
+    /// `None` when the error concerns the whole codice fiscale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let error = CodiceFiscale::new("BLTMHL77S04E8Y9G").unwrap_err();
+    /// assert_eq!(error.span(), Some(13..14));
+    ///
+    /// let error = CodiceFiscale::new("BLTMHL77S04").unwrap_err();
+    /// assert_eq!(error.span(), None);
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            VerifierError::InvalidOmocodeCharacter(character, position)
+            | VerifierError::NonAlphanumericCharacter(character, position) => {
+                Some(*position..*position + character.len_utf8())
+            }
+            VerifierError::LowercaseCharacter(position) => Some(*position..*position + 1),
+            VerifierError::InvalidBirthDate(_, _, _)
+            | VerifierError::UnresolvedBirthDate(_, _, _)
+            | VerifierError::FutureBirthDate(_, _)
+            | VerifierError::ImplausibleAge(_, _, _) => Some(BIRTH_DATE_PART),
+            error => error.segment().map(|segment| segment.range()),
+        }
+    }
+}
+
+/// A problem found verifying a codice fiscale, together with the segment and the
+/// positions of the characters it refers to.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{error}")]
+pub struct VerificationIssue {
+    pub(crate) error: VerifierError,
+    pub(crate) segment: Option<Segment>,
+    pub(crate) span: Range<usize>,
+}
+
+impl VerificationIssue {
+    pub fn error(&self) -> &VerifierError {
+        &self.error
+    }
+
+    /// The segment the issue refers to, `None` when it concerns the whole codice fiscale
+    /// or more than one segment.
+    pub fn segment(&self) -> Option<Segment> {
+        self.segment
+    }
+
+    /// The byte positions of the wrong characters within the codice fiscale.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl From<VerifierError> for VerificationIssue {
+    fn from(error: VerifierError) -> Self {
+        let span = match error {
+            VerifierError::InvalidLength(length) => 0..length,
+            ref error => error.span().unwrap_or_default(),
+        };
+
+        VerificationIssue {
+            segment: error.segment(),
+            error,
+            span,
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum OmocodeError {
+    #[error("The omocode level should be between 0 and 7 instead is `{0}`")]
+    InvalidLevel(usize),
+    #[error("The position `{0}` does not hold a digit that can be replaced by an omocode letter")]
+    InvalidPosition(usize),
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CompletionError {
+    #[error("The fiscal code length should be 16 instead is `{0}`")]
+    InvalidLength(usize),
+    #[error("At most 2 characters can be unknown instead they are `{0}`")]
+    TooManyUnknownCharacters(usize),
 }
@@ -10,6 +10,7 @@ use rand::{
 use crate::{
     common,
     control_code::ControlCode,
+    errors::OmocodeError,
     omocodes::Omocodes,
     person_data::{Gender, PersonData},
 };
@@ -18,41 +19,70 @@ pub struct Generator {}
 
 impl Generator {
     pub fn generate(person_data: &PersonData) -> String {
-        let surname_part = generate_name_or_surname_part(person_data.surname());
-        let name_part = generate_name_or_surname_part(person_data.name());
-        let birth_day_and_gender_part =
-            generate_birth_day_and_gender_parts(person_data.birthdate(), person_data.gender());
-
         let mut codice_fiscale: String = [
-            surname_part,
-            name_part,
-            birth_day_and_gender_part,
-            person_data.birth_place().chars().collect(),
+            Generator::generate_surname_part(&person_data.surname()),
+            Generator::generate_name_part(&person_data.name()),
+            Generator::generate_birth_date_part(person_data.birthdate(), person_data.gender()),
+            person_data.birth_place().to_uppercase(),
         ]
-        .concat()
-        .iter()
-        .collect();
+        .concat();
 
         codice_fiscale.push(ControlCode::compute(&codice_fiscale));
         codice_fiscale
     }
 
+    /// The three letters encoding the surname, normalized following the Circolare 34/E.
+    pub fn generate_surname_part(surname: &str) -> String {
+        generate_surname_part(common::normalize_name_or_surname(surname))
+            .iter()
+            .collect()
+    }
+
+    /// The three letters encoding the name, normalized following the Circolare 34/E.
+    pub fn generate_name_part(name: &str) -> String {
+        generate_name_part(common::normalize_name_or_surname(name))
+            .iter()
+            .collect()
+    }
+
+    /// The birth year, month and day, the latter increased by 40 for women.
+    pub fn generate_birth_date_part(birthdate: NaiveDate, gender: Gender) -> String {
+        generate_birth_day_and_gender_parts(birthdate, gender)
+            .iter()
+            .collect()
+    }
+
+    pub fn generate_omocode(
+        person_data: &PersonData,
+        positions: &[usize],
+    ) -> Result<String, OmocodeError> {
+        let codice_fiscale = Generator::generate(person_data);
+        let omocode = Omocodes::generate_at(codice_fiscale.chars(), positions)?;
+
+        Ok(with_control_code(omocode))
+    }
+
     pub fn generate_omocodes(starting_codice_fiscale: &str) -> Vec<String> {
-        let mut omocodes = Omocodes::generate(
+        let omocodes = Omocodes::generate(
             Omocodes::replace_omocodes_characters(starting_codice_fiscale).chars(),
         );
 
-        omocodes
-            .iter_mut()
-            .map(|omocode| {
-                let control_code = ControlCode::compute(&omocode.iter().collect::<String>());
-                if omocode.len() == 16 {
-                    omocode.pop();
-                }
-                omocode.push(control_code);
-                omocode.iter().collect::<String>()
-            })
-            .collect::<Vec<String>>()
+        omocodes.into_iter().map(with_control_code).collect()
+    }
+
+    pub fn generate_all_omocodes(starting_codice_fiscale: &str) -> Vec<String> {
+        let omocodes = Omocodes::generate_all(
+            Omocodes::replace_omocodes_characters(starting_codice_fiscale).chars(),
+        );
+
+        omocodes.into_iter().map(with_control_code).collect()
+    }
+
+    pub fn generate_canonical(codice_fiscale: &str) -> String {
+        let purified_codice_fiscale =
+            Omocodes::replace_omocodes_characters(codice_fiscale).to_uppercase();
+
+        with_control_code(purified_codice_fiscale.chars().collect())
     }
 
     pub fn generate_random(seed: Option<u64>) -> String {
@@ -96,6 +126,31 @@ impl Generator {
     }
 }
 
+/// Replace the control character of the codice fiscale with the one computed from the others.
+fn with_control_code(mut codice_fiscale: Vec<char>) -> String {
+    let control_code = ControlCode::compute(&codice_fiscale.iter().collect::<String>());
+    if codice_fiscale.len() == 16 {
+        codice_fiscale.pop();
+    }
+    codice_fiscale.push(control_code);
+    codice_fiscale.iter().collect()
+}
+
+fn generate_surname_part(surname: String) -> Vec<char> {
+    generate_name_or_surname_part(surname)
+}
+
+/// The name follows the same rule of the surname, except when it contains
+/// four or more consonants: in that case the first, third and fourth are used.
+fn generate_name_part(name: String) -> Vec<char> {
+    let consonants: Vec<char> = name.chars().filter(common::is_consonant).collect();
+    if consonants.len() >= 4 {
+        return vec![consonants[0], consonants[2], consonants[3]];
+    }
+
+    generate_name_or_surname_part(name)
+}
+
 fn generate_name_or_surname_part(value: String) -> Vec<char> {
     let mut result = vec![];
 
@@ -116,7 +171,9 @@ fn generate_name_or_surname_part(value: String) -> Vec<char> {
 }
 
 fn generate_birth_day_and_gender_parts(birthday: NaiveDate, gender: Gender) -> Vec<char> {
-    let year_part: Vec<char> = birthday.year().to_string().chars().collect();
+    let year_part: Vec<char> = format!("{:02}", birthday.year().rem_euclid(100))
+        .chars()
+        .collect();
     let month_part = common::to_month_codes((birthday.month() as usize) - 1);
 
     let mut day_part: Vec<char> = match gender {
@@ -128,7 +185,7 @@ fn generate_birth_day_and_gender_parts(birthday: NaiveDate, gender: Gender) -> V
         day_part.insert(0, '0');
     }
 
-    [year_part[2..].to_vec(), vec![month_part], day_part].concat()
+    [year_part, vec![month_part], day_part].concat()
 }
 
 #[cfg(test)]
@@ -145,6 +202,120 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generate_name_part_four_consonants() {
+        assert_eq!(
+            generate_name_part("GIANFRANCO".to_string()),
+            vec!['G', 'F', 'R']
+        );
+        assert_eq!(
+            generate_name_part("GIOVANNI".to_string()),
+            vec!['G', 'N', 'N']
+        );
+        assert_eq!(
+            generate_name_part("ALBERTO".to_string()),
+            vec!['L', 'R', 'T']
+        );
+    }
+
+    #[test]
+    fn generate_name_part_three_consonants() {
+        assert_eq!(generate_name_part("MARCO".to_string()), vec!['M', 'R', 'C']);
+        assert_eq!(generate_name_part("MARIO".to_string()), vec!['M', 'R', 'A']);
+        assert_eq!(generate_name_part("EVA".to_string()), vec!['V', 'E', 'A']);
+        assert_eq!(generate_name_part("IO".to_string()), vec!['I', 'O', 'X']);
+    }
+
+    #[test]
+    fn generate_surname_part_four_consonants() {
+        assert_eq!(
+            generate_surname_part("BIANCHI".to_string()),
+            vec!['B', 'N', 'C']
+        );
+        assert_eq!(
+            generate_surname_part("ROSSI".to_string()),
+            vec!['R', 'S', 'S']
+        );
+    }
+
+    #[test]
+    fn generate_valid_codice_fiscale_well_known() {
+        let person_data = PersonData::new(
+            "MARIO".to_string(),
+            "ROSSI".to_string(),
+            NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+            Gender::M,
+            "H501".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(Generator::generate(&person_data), "RSSMRA80A01H501U");
+    }
+
+    #[test]
+    fn generate_valid_codice_fiscale_short_year() {
+        let person_data = PersonData::new(
+            "MARIO".to_string(),
+            "ROSSI".to_string(),
+            NaiveDate::from_ymd_opt(5, 1, 1).unwrap(),
+            Gender::M,
+            "H501".to_string(),
+        )
+        .unwrap();
+        let codice_fiscale = Generator::generate(&person_data);
+
+        assert_eq!(&codice_fiscale[6..8], "05");
+        assert!(CodiceFiscale::new(&codice_fiscale).is_ok());
+    }
+
+    #[test]
+    fn generate_valid_codice_fiscale_name_four_consonants() {
+        let person_data = PersonData::new(
+            "GIANFRANCO".to_string(),
+            "ROSSI".to_string(),
+            NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+            Gender::M,
+            "H501".to_string(),
+        )
+        .unwrap();
+        let codice_fiscale = Generator::generate(&person_data);
+
+        assert_eq!(&codice_fiscale[0..6], "RSSGFR");
+        assert!(CodiceFiscale::new(&codice_fiscale).is_ok());
+    }
+
+    #[test]
+    fn generate_valid_codice_fiscale_special_characters() {
+        let person_data = PersonData::new(
+            "NICOLÒ".to_string(),
+            "D'ANGELO".to_string(),
+            NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+            Gender::M,
+            "H501".to_string(),
+        )
+        .unwrap();
+        let codice_fiscale = Generator::generate(&person_data);
+
+        assert_eq!(&codice_fiscale[0..6], "DNGNCL");
+        assert!(CodiceFiscale::new(&codice_fiscale).is_ok());
+    }
+
+    #[test]
+    fn generate_valid_codice_fiscale_multi_part_surname() {
+        let person_data = PersonData::new(
+            "ANNA MARIA".to_string(),
+            "DE LUCA".to_string(),
+            NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+            Gender::F,
+            "H501".to_string(),
+        )
+        .unwrap();
+        let codice_fiscale = Generator::generate(&person_data);
+
+        assert_eq!(&codice_fiscale[0..6], "DLCNMR");
+        assert!(CodiceFiscale::new(&codice_fiscale).is_ok());
+    }
+
     #[test]
     fn generate_valid_codice_fiscale_from_person_data_female() {
         let person_data = PersonData::new(
@@ -13,19 +13,45 @@
 
 use std::fmt;
 
-use errors::VerifierError;
+#[cfg(feature = "registry")]
+use century_policy::CenturyPolicy;
+use chrono::NaiveDate;
+use clock::Clock;
+use decoder::DecodedCodiceFiscale;
+use errors::{CompletionError, OmocodeError, PersonDataError, VerificationIssue, VerifierError};
 use generator::Generator;
+use matching::MatchReport;
+use normalization::Normalized;
 use omocodes::Omocodes;
-use person_data::PersonData;
+use person_data::{Gender, PersonData};
+use plausibility::Plausibility;
+use suggestions::Suggestion;
+use transliteration::{Transliteration, TransliterationTable};
 use verifier::Verifier;
+use verifier_options::VerifierOptions;
 
+pub mod century_policy;
+pub mod clock;
 mod common;
+mod completion;
 mod control_code;
+#[cfg(feature = "registry")]
+pub mod countries;
+pub mod decoder;
 pub mod errors;
 mod generator;
+pub mod matching;
+#[cfg(feature = "registry")]
+pub mod municipalities;
+pub mod normalization;
 mod omocodes;
 pub mod person_data;
+pub mod plausibility;
+pub mod segment;
+pub mod suggestions;
+pub mod transliteration;
 mod verifier;
+pub mod verifier_options;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct CodiceFiscale {
@@ -44,6 +70,7 @@ impl CodiceFiscale {
     /// otherwise returns an error of type VerifierError.
     /// It detects the presence of omocode characters and considers it valid if the mapping,
     /// that you can find here <https://it.wikipedia.org/wiki/Omocodia> is satisfied.
+    /// The codice fiscale is stored in uppercase.
     ///
     /// # Examples
     ///
@@ -74,12 +101,144 @@ impl CodiceFiscale {
     ///
     /// This function will return an error if the referenced fiscal code is not valid.
     pub fn new(raw_codice_fiscale: &str) -> Result<CodiceFiscale, VerifierError> {
-        let codice_fiscale = CodiceFiscale {
-            codice_fiscale: raw_codice_fiscale.to_string(),
-        };
+        Verifier::verify(raw_codice_fiscale)?;
+
+        Ok(CodiceFiscale {
+            codice_fiscale: raw_codice_fiscale.to_uppercase(),
+        })
+    }
+
+    /// **Static** create a new CodiceFiscale struct from a value that can contain lowercase
+    /// letters, whitespace and the separators `-`, `.`, `/` and `_`, as when it's pasted from
+    /// a document. The value is normalized before the verification and the changes done
+    /// are returned together with the codice fiscale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::normalization::Change;
+    ///
+    /// let normalized = CodiceFiscale::parse("BLT-MHL-77S04-E889g").unwrap();
+    /// assert_eq!(normalized.codice_fiscale().get(), "BLTMHL77S04E889G");
+    /// assert_eq!(normalized.changes().len(), 4);
+    /// assert_eq!(
+    ///     normalized.changes()[3],
+    ///     Change::Uppercased { position: 18, character: 'g' }
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the normalized fiscal code is not valid,
+    /// the positions in the error refer to the normalized value.
+    pub fn parse(raw_codice_fiscale: &str) -> Result<Normalized, VerifierError> {
+        let (normalized_codice_fiscale, changes) = normalization::normalize(raw_codice_fiscale);
+        let codice_fiscale = CodiceFiscale::new(&normalized_codice_fiscale)?;
+
+        Ok(Normalized::new(codice_fiscale, changes))
+    }
+
+    /// **Static** create a new CodiceFiscale struct with the provided string value, verified
+    /// following the provided options. The accepted whitespace is not stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::errors::VerifierError;
+    /// use codice_fiscale_rs::verifier_options::VerifierOptions;
+    ///
+    /// let codice_fiscale =
+    ///     CodiceFiscale::new_with_options(" BLTMHL 77S04 E889G ", &VerifierOptions::lenient());
+    /// assert_eq!(codice_fiscale.unwrap().get(), "BLTMHL77S04E889G");
+    ///
+    /// let outcome = CodiceFiscale::new_with_options("bLTMHL77S04E889G", &VerifierOptions::strict());
+    /// assert_eq!(outcome, Err(VerifierError::LowercaseCharacter(0)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the referenced fiscal code is not valid
+    /// following the options.
+    pub fn new_with_options(
+        raw_codice_fiscale: &str,
+        options: &VerifierOptions,
+    ) -> Result<CodiceFiscale, VerifierError> {
+        let codice_fiscale = options.verify(raw_codice_fiscale)?.to_uppercase();
+        Ok(CodiceFiscale { codice_fiscale })
+    }
+
+    /// **Static** create a new CodiceFiscale struct like [`CodiceFiscale::new`], but instead
+    /// of stopping at the first error it returns every problem found, each one with the segment
+    /// and the positions of the characters it refers to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::segment::Segment;
+    ///
+    /// let issues = CodiceFiscale::verify_all("BLTMHL77X04E8Y9G").unwrap_err();
+    /// assert_eq!(issues[0].segment(), Some(Segment::BirthMonth));
+    /// assert_eq!(issues[1].span(), 13..14);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return the list of issues if the referenced fiscal code is not valid.
+    pub fn verify_all(raw_codice_fiscale: &str) -> Result<CodiceFiscale, Vec<VerificationIssue>> {
+        let issues = Verifier::verify_all(raw_codice_fiscale);
+        if !issues.is_empty() {
+            return Err(issues);
+        }
+
+        Ok(CodiceFiscale {
+            codice_fiscale: raw_codice_fiscale.to_uppercase(),
+        })
+    }
 
-        Verifier::verify(&codice_fiscale.get())?;
-        Ok(codice_fiscale)
+    /// **Static** method returns the valid codici fiscali that differ from the provided one by
+    /// a single wrong character or by two swapped adjacent characters, useful when the
+    /// verification fails because of a typo. Suggestions are ranked from the most likely,
+    /// replacing characters that look alike (e.g. `O` and `0`), to the least likely.
+    /// A valid codice fiscale has no suggestions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let suggestions = CodiceFiscale::suggestions("BLTMHL77S04E8B9G");
+    /// assert_eq!(suggestions[0].codice_fiscale().get(), "BLTMHL77S04E889G");
+    /// ```
+    pub fn suggestions(raw_codice_fiscale: &str) -> Vec<Suggestion> {
+        suggestions::suggest(raw_codice_fiscale)
+    }
+
+    /// **Static** method returns every valid codice fiscale matching the provided one, where
+    /// up to two illegible characters are marked with a `?` wildcard. Each wildcard is replaced
+    /// only by the characters allowed in its position and the candidates are kept if they pass
+    /// the verification, including the control character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let completions = CodiceFiscale::complete("BLTMHL77S04E88?G").unwrap();
+    /// assert!(completions.contains(&CodiceFiscale::new("BLTMHL77S04E889G").unwrap()));
+    ///
+    /// let completions = CodiceFiscale::complete("BLTMHL77S04E889?").unwrap();
+    /// assert_eq!(completions, vec![CodiceFiscale::new("BLTMHL77S04E889G").unwrap()]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the codice fiscale is not 16 characters long
+    /// or more than two characters are unknown.
+    pub fn complete(raw_codice_fiscale: &str) -> Result<Vec<CodiceFiscale>, CompletionError> {
+        completion::complete(raw_codice_fiscale)
     }
 
     /// **Static** method returns a CodiceFiscale struct from the personal data that
@@ -109,6 +268,128 @@ impl CodiceFiscale {
         CodiceFiscale { codice_fiscale }
     }
 
+    /// **Static** method returns the omocode at the provided level of the codice fiscale
+    /// generated from the personal data, following the cumulative substitution order from right
+    /// to left. Level 0 is the codice fiscale returned by [`CodiceFiscale::generate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::person_data::{Gender, PersonData};
+    /// use chrono::NaiveDate;
+    ///
+    /// let person_data = PersonData::new(
+    ///     "PIPPO".to_string(),
+    ///     "PLUTO".to_string(),
+    ///     NaiveDate::from_ymd_opt(2023, 1, 7).unwrap(),
+    ///     Gender::M,
+    ///     "B544".to_string()).unwrap();
+    ///
+    /// let omocode = CodiceFiscale::generate_omocode(&person_data, 2).unwrap();
+    /// assert_eq!(omocode.get(), "PLTPPP23A07B5QQT");
+    /// assert_eq!(omocode.omocode_level(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the level is greater than 7.
+    pub fn generate_omocode(
+        person_data: &PersonData,
+        level: usize,
+    ) -> Result<CodiceFiscale, OmocodeError> {
+        CodiceFiscale::generate_omocode_at(person_data, &Omocodes::positions_at_level(level)?)
+    }
+
+    /// **Static** method returns the omocode of the codice fiscale generated from the personal
+    /// data where the digits at the provided positions are replaced by their omocode letter.
+    /// Positions are zero based, the replaceable ones are 6, 7, 9, 10, 12, 13 and 14; a repeated
+    /// position is replaced once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::person_data::{Gender, PersonData};
+    /// use chrono::NaiveDate;
+    ///
+    /// let person_data = PersonData::new(
+    ///     "PIPPO".to_string(),
+    ///     "PLUTO".to_string(),
+    ///     NaiveDate::from_ymd_opt(2023, 1, 7).unwrap(),
+    ///     Gender::M,
+    ///     "B544".to_string()).unwrap();
+    ///
+    /// let omocode = CodiceFiscale::generate_omocode_at(&person_data, &[6, 13]).unwrap();
+    /// assert_eq!(omocode.get(), "PLTPPPN3A07B5Q4L");
+    /// assert_eq!(omocode.omocode_positions(), vec![6, 13]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a position can't hold an omocode letter.
+    pub fn generate_omocode_at(
+        person_data: &PersonData,
+        positions: &[usize],
+    ) -> Result<CodiceFiscale, OmocodeError> {
+        let codice_fiscale = Generator::generate_omocode(person_data, positions)?;
+        Ok(CodiceFiscale { codice_fiscale })
+    }
+
+    /// **Static** method returns the codice fiscale of a person whose name or surname are written
+    /// with non latin letters, transliterated with the provided table. The transliterated name
+    /// and surname are returned together with the codice fiscale, so they can be confirmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::person_data::Gender;
+    /// use codice_fiscale_rs::transliteration::TransliterationTable;
+    /// use chrono::NaiveDate;
+    ///
+    /// let transliteration = CodiceFiscale::generate_transliterated(
+    ///     "Αθηνά".to_string(),
+    ///     "Παπαδόπουλος".to_string(),
+    ///     NaiveDate::from_ymd_opt(1985, 6, 20).unwrap(),
+    ///     Gender::F,
+    ///     "Z115".to_string(),
+    ///     &TransliterationTable::default(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(transliteration.name(), "ATHINA");
+    /// assert_eq!(transliteration.surname(), "PAPADOPOULOS");
+    /// assert_eq!(&transliteration.codice_fiscale().get()[0..6], "PPDTHN");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the transliterated name or surname are not valid
+    /// or the birth place is not shaped as a codice catastale.
+    pub fn generate_transliterated(
+        name: String,
+        surname: String,
+        birthdate: NaiveDate,
+        gender: Gender,
+        place_of_birth: String,
+        table: &TransliterationTable,
+    ) -> Result<Transliteration, PersonDataError> {
+        let person_data = PersonData::new_transliterated(
+            name,
+            surname,
+            birthdate,
+            gender,
+            place_of_birth,
+            table,
+        )?;
+        let codice_fiscale = CodiceFiscale::generate(&person_data);
+
+        Ok(Transliteration {
+            person_data,
+            codice_fiscale,
+        })
+    }
+
     /// Generate a random fiscal code. It's possible to provide a seed
     /// as an argument to generate predictable sequence of codici fiscali.
     pub fn generate_random(seed: Option<u64>) -> CodiceFiscale {
@@ -120,14 +401,300 @@ impl CodiceFiscale {
         self.get() != Omocodes::replace_omocodes_characters(&self.get())
     }
 
+    /// Returns the 7 omocodes obtained replacing the digits with letters cumulatively,
+    /// from right to left, starting from the codice fiscale without omocode characters.
     pub fn omocodes(&self) -> Vec<CodiceFiscale> {
-        let omocodes = Generator::generate_omocodes(&self.get());
-        omocodes
-            .iter()
-            .map(|cf| CodiceFiscale::new(cf).unwrap())
+        Generator::generate_omocodes(&self.get())
+            .into_iter()
+            .map(|codice_fiscale| CodiceFiscale { codice_fiscale })
             .collect()
     }
 
+    /// Returns the positions, in ascending order, of the digits replaced by an omocode letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H5LMX").unwrap();
+    /// assert_eq!(codice_fiscale.omocode_positions(), vec![13, 14]);
+    /// ```
+    pub fn omocode_positions(&self) -> Vec<usize> {
+        Omocodes::try_replace_omocodes_characters(&self.get())
+            .map(|(_, omocode_positions)| omocode_positions)
+            .unwrap_or_default()
+    }
+
+    /// Returns the omocode level, that is the number of digits replaced by an omocode letter.
+    /// It's 0 if the codice fiscale is not an omocode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// assert_eq!(CodiceFiscale::new("RSSMRA80A01H501U").unwrap().omocode_level(), 0);
+    /// assert_eq!(CodiceFiscale::new("RSSMRA80A01H5LMX").unwrap().omocode_level(), 2);
+    /// ```
+    pub fn omocode_level(&self) -> usize {
+        self.omocode_positions().len()
+    }
+
+    /// Returns the omocode at the provided level, following the cumulative substitution
+    /// order from right to left. Level 0 is the canonical form of the codice fiscale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+    /// assert_eq!(codice_fiscale.omocode(2).unwrap().get(), "RSSMRA80A01H5LMX");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the level is greater than 7.
+    pub fn omocode(&self, level: usize) -> Result<CodiceFiscale, OmocodeError> {
+        if level == 0 {
+            return Ok(self.canonical());
+        }
+
+        self.omocodes()
+            .into_iter()
+            .nth(level - 1)
+            .ok_or(OmocodeError::InvalidLevel(level))
+    }
+
+    /// Returns every omocode of the codice fiscale: Agenzia delle Entrate can replace any subset
+    /// of the 7 digits with a letter, so there are 127 of them. They are ordered by the number
+    /// of replaced digits and each one has its own control character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let omocodes = CodiceFiscale::new("RSSMRA80A01H501U").unwrap().all_omocodes();
+    /// assert_eq!(omocodes.len(), 127);
+    /// assert_eq!(omocodes[0].get(), "RSSMRA80A01H50MM");
+    /// assert!(omocodes.iter().all(|omocode| CodiceFiscale::new(&omocode.get()).is_ok()));
+    /// ```
+    pub fn all_omocodes(&self) -> Vec<CodiceFiscale> {
+        Generator::generate_all_omocodes(&self.get())
+            .into_iter()
+            .map(|codice_fiscale| CodiceFiscale { codice_fiscale })
+            .collect()
+    }
+
+    /// Returns the canonical form of the codice fiscale: uppercase, with all the omocode
+    /// characters mapped back to digits and the control character computed again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H50MM").unwrap();
+    /// assert_eq!(codice_fiscale.canonical().get(), "RSSMRA80A01H501U");
+    /// ```
+    pub fn canonical(&self) -> CodiceFiscale {
+        CodiceFiscale {
+            codice_fiscale: Generator::generate_canonical(&self.get()),
+        }
+    }
+
+    /// Returns true if the two codici fiscali belong to the same base identity, that is
+    /// they have the same canonical form. A codice fiscale is an omocode of itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+    /// let omocode = CodiceFiscale::new("RSSMRA80A01H50MM").unwrap();
+    /// let other = CodiceFiscale::new("RSSMRA80A01F205X").unwrap();
+    /// assert!(omocode.is_omocode_of(&codice_fiscale));
+    /// assert!(!other.is_omocode_of(&codice_fiscale));
+    /// ```
+    pub fn is_omocode_of(&self, other: &CodiceFiscale) -> bool {
+        self.canonical() == other.canonical()
+    }
+
+    /// Decode the codice fiscale into its structured parts: surname and name letters,
+    /// birth year digits, birth month, birth day, gender, birth place and control character.
+    /// Omocode characters are mapped back to digits before being interpreted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::person_data::Gender;
+    ///
+    /// let decoded = CodiceFiscale::new("BRNPRZ72D52F83VC").unwrap().decode().unwrap();
+    /// assert_eq!(decoded.birth_year(), 72);
+    /// assert_eq!(decoded.birth_month(), 4);
+    /// assert_eq!(decoded.birth_day(), 12);
+    /// assert_eq!(decoded.gender(), Gender::F);
+    /// assert_eq!(decoded.birth_place(), "F839");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the fiscal code is not valid.
+    pub fn decode(&self) -> Result<DecodedCodiceFiscale, VerifierError> {
+        Verifier::decode(&self.get())
+    }
+
+    /// Compares the codice fiscale with the personal data declared by its owner, returning
+    /// the comparison of each field. Omocodes are compared after mapping the omocode
+    /// characters back to digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::person_data::{Gender, PersonData};
+    /// use chrono::NaiveDate;
+    ///
+    /// let person_data = PersonData::new(
+    ///     "MICHELE".to_string(),
+    ///     "BELTRAMI".to_string(),
+    ///     NaiveDate::from_ymd_opt(1977, 11, 14).unwrap(),
+    ///     Gender::M,
+    ///     "E889".to_string()).unwrap();
+    ///
+    /// let report = CodiceFiscale::new("BLTMHL77S04E889G").unwrap().matches(&person_data);
+    /// assert!(!report.is_match());
+    /// assert!(report.name().is_match());
+    /// assert_eq!(report.birthdate().expected(), "77S14");
+    /// assert_eq!(report.birthdate().found(), "77S04");
+    /// ```
+    pub fn matches(&self, person_data: &PersonData) -> MatchReport {
+        MatchReport::new(self, person_data)
+    }
+
+    /// Checks that the birth date is plausible on the date returned by the clock: it must not be
+    /// in the future and it must not imply an age over the maximum. Returns the birth date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::clock::FixedClock;
+    /// use codice_fiscale_rs::errors::VerifierError;
+    /// use codice_fiscale_rs::plausibility::Plausibility;
+    /// use chrono::NaiveDate;
+    ///
+    /// let clock = FixedClock(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+    /// let codice_fiscale = CodiceFiscale::new("BLTMHL77S04E889G").unwrap();
+    ///
+    /// assert_eq!(
+    ///     codice_fiscale.verify_plausibility(&Plausibility::default(), &clock),
+    ///     Ok(NaiveDate::from_ymd_opt(1977, 11, 4).unwrap())
+    /// );
+    /// assert!(matches!(
+    ///     codice_fiscale.verify_plausibility(&Plausibility::new(40), &clock),
+    ///     Err(VerifierError::ImplausibleAge(_, 45, 40))
+    /// ));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the birth date is not plausible.
+    pub fn verify_plausibility(
+        &self,
+        plausibility: &Plausibility,
+        clock: &impl Clock,
+    ) -> Result<NaiveDate, VerifierError> {
+        plausibility.check(&self.decode()?, clock)
+    }
+
+    /// Checks that the birth place of the codice fiscale is an existing municipality
+    /// or foreign country, using the registries embedded in the crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::errors::VerifierError;
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+    /// assert_eq!(codice_fiscale.verify_birth_place(), Ok(()));
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01A000C").unwrap();
+    /// assert_eq!(
+    ///     codice_fiscale.verify_birth_place(),
+    ///     Err(VerifierError::UnknownBirthPlace("A000".to_string()))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the birth place is not in the registry.
+    #[cfg(feature = "registry")]
+    pub fn verify_birth_place(&self) -> Result<(), VerifierError> {
+        let decoded = self.decode()?;
+        verifier::verify_birth_place_exists(&decoded.birth_place())
+    }
+
+    /// Checks that the birth place of the codice fiscale is a municipality that existed on the
+    /// birth date, using the registry embedded in the crate. Every birth date allowed by the
+    /// provided century policy is tried, the birth place is accepted if it existed on any of
+    /// them. Foreign countries are only checked for existence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::CodiceFiscale;
+    /// use codice_fiscale_rs::century_policy::CenturyPolicy;
+    /// use codice_fiscale_rs::errors::VerifierError;
+    /// use chrono::NaiveDate;
+    ///
+    /// let codice_fiscale = CodiceFiscale::new("RSSMRA80A01M403C").unwrap();
+    /// assert_eq!(
+    ///     codice_fiscale.verify_birth_place_on_birthdate(&CenturyPolicy::Pivot(30)),
+    ///     Err(VerifierError::InactiveBirthPlace(
+    ///         "M403".to_string(),
+    ///         NaiveDate::from_ymd_opt(1980, 1, 1).unwrap()
+    ///     ))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the birth place is not in the registry, it did not
+    /// exist on any of the birth dates, reporting the most recent one, or the policy allows
+    /// no birth date at all.
+    #[cfg(feature = "registry")]
+    pub fn verify_birth_place_on_birthdate(
+        &self,
+        policy: &CenturyPolicy,
+    ) -> Result<(), VerifierError> {
+        let decoded = self.decode()?;
+        let birth_place = decoded.birth_place();
+        let mut outcomes = decoded
+            .birthdate_candidates(policy)
+            .into_iter()
+            .rev()
+            .map(|birthdate| verifier::verify_birth_place_active(&birth_place, birthdate));
+
+        let most_recent = outcomes.next().unwrap_or_else(|| {
+            Err(VerifierError::UnresolvedBirthDate(
+                decoded.birth_year(),
+                decoded.birth_month(),
+                decoded.birth_day(),
+            ))
+        });
+        match most_recent.is_ok() || outcomes.any(|outcome| outcome.is_ok()) {
+            true => Ok(()),
+            false => most_recent,
+        }
+    }
+
+    /// Returns the codice fiscale, always in uppercase.
     pub fn get(&self) -> String {
         self.codice_fiscale.to_string()
     }
@@ -135,9 +702,7 @@ impl CodiceFiscale {
 
 #[cfg(test)]
 mod tests {
-    use chrono::NaiveDate;
-
-    use crate::person_data::Gender;
+    use crate::century_policy::CenturyPolicy;
 
     use super::*;
 
@@ -154,6 +719,61 @@ mod tests {
         assert!(CodiceFiscale::new("PLTPPP23A47T567Q").is_ok());
     }
 
+    #[cfg(feature = "registry")]
+    #[test]
+    fn test_verify_birth_place_on_any_birthdate() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRA19A01D005H").unwrap();
+        let reference = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+
+        assert_eq!(
+            codice_fiscale.verify_birth_place_on_birthdate(&CenturyPolicy::MaxAge {
+                reference,
+                max_age: 120
+            }),
+            Ok(())
+        );
+        assert_eq!(
+            codice_fiscale.verify_birth_place_on_birthdate(&CenturyPolicy::RelativeTo(reference)),
+            Err(VerifierError::InactiveBirthPlace(
+                "D005".to_string(),
+                NaiveDate::from_ymd_opt(2019, 1, 1).unwrap()
+            ))
+        );
+        assert_eq!(
+            codice_fiscale.verify_birth_place_on_birthdate(&CenturyPolicy::MaxAge {
+                reference,
+                max_age: 1
+            }),
+            Err(VerifierError::UnresolvedBirthDate(19, 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_new_stores_uppercase() {
+        let codice_fiscale = CodiceFiscale::new("cTMTBT74E05B506W").unwrap();
+
+        assert_eq!(codice_fiscale.get(), "CTMTBT74E05B506W");
+        assert_eq!(
+            codice_fiscale,
+            CodiceFiscale::new("CTMTBT74E05B506W").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let normalized = CodiceFiscale::parse(" rss mra 80a01 h501u ").unwrap();
+
+        assert_eq!(normalized.codice_fiscale().get(), "RSSMRA80A01H501U");
+        assert!(!normalized.is_unchanged());
+        assert!(CodiceFiscale::parse("RSSMRA80A01H501U")
+            .unwrap()
+            .is_unchanged());
+        assert_eq!(
+            CodiceFiscale::parse("RSS-MRA-80A01-H501"),
+            Err(VerifierError::InvalidLength(15))
+        );
+    }
+
     #[test]
     fn test_generate() {
         let naive_now = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
@@ -171,6 +791,130 @@ mod tests {
         assert_eq!(codice_fiscale.get(), "PLTPPP22R42T567K".to_string());
     }
 
+    #[test]
+    fn test_generate_omocode() {
+        let person_data = PersonData::new(
+            "PIPPO".to_string(),
+            "PLUTO".to_string(),
+            NaiveDate::from_ymd_opt(2022, 10, 2).unwrap(),
+            Gender::F,
+            "T567".to_string(),
+        )
+        .unwrap();
+        let codice_fiscale = CodiceFiscale::generate(&person_data);
+
+        assert_eq!(
+            CodiceFiscale::generate_omocode(&person_data, 0),
+            Ok(CodiceFiscale::generate(&person_data))
+        );
+        for (level, omocode) in codice_fiscale.omocodes().into_iter().enumerate() {
+            assert_eq!(
+                CodiceFiscale::generate_omocode(&person_data, level + 1),
+                Ok(omocode)
+            );
+        }
+        assert_eq!(
+            CodiceFiscale::generate_omocode(&person_data, 8),
+            Err(OmocodeError::InvalidLevel(8))
+        );
+    }
+
+    #[test]
+    fn test_generate_omocode_at() {
+        let person_data = PersonData::new(
+            "PIPPO".to_string(),
+            "PLUTO".to_string(),
+            NaiveDate::from_ymd_opt(2022, 10, 2).unwrap(),
+            Gender::F,
+            "T567".to_string(),
+        )
+        .unwrap();
+
+        let omocode = CodiceFiscale::generate_omocode_at(&person_data, &[7, 12]).unwrap();
+
+        assert!(CodiceFiscale::new(&omocode.get()).is_ok());
+        assert_eq!(omocode.omocode_positions(), vec![7, 12]);
+        assert!(omocode.is_omocode_of(&CodiceFiscale::generate(&person_data)));
+        assert_eq!(
+            CodiceFiscale::generate_omocode_at(&person_data, &[11]),
+            Err(OmocodeError::InvalidPosition(11))
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let decoded = CodiceFiscale::new("PLTPPP22R42T567K")
+            .unwrap()
+            .decode()
+            .unwrap();
+
+        assert_eq!(decoded.surname(), "PLT");
+        assert_eq!(decoded.name(), "PPP");
+        assert_eq!(decoded.birth_year(), 22);
+        assert_eq!(decoded.birth_month(), 10);
+        assert_eq!(decoded.birth_day(), 2);
+        assert_eq!(decoded.gender(), Gender::F);
+        assert_eq!(decoded.birth_place(), "T567");
+        assert_eq!(decoded.control_character(), 'K');
+    }
+
+    #[test]
+    fn test_decode_birthdate_candidates() {
+        let decoded = CodiceFiscale::new("PLTPPP22R42T567K")
+            .unwrap()
+            .decode()
+            .unwrap();
+        let policy = CenturyPolicy::MaxAge {
+            reference: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            max_age: 110,
+        };
+
+        assert_eq!(
+            decoded.birthdate_candidates(&policy),
+            vec![
+                NaiveDate::from_ymd_opt(1922, 10, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 10, 2).unwrap()
+            ]
+        );
+        assert_eq!(
+            decoded.birthdate(&policy),
+            NaiveDate::from_ymd_opt(2022, 10, 2)
+        );
+    }
+
+    #[test]
+    fn test_generate_mixed_case() {
+        let person_data = PersonData::new(
+            "Mario".to_string(),
+            "rossi".to_string(),
+            NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+            Gender::M,
+            "h501".to_string(),
+        )
+        .unwrap();
+
+        let codice_fiscale = CodiceFiscale::generate(&person_data);
+
+        assert_eq!(codice_fiscale.get(), "RSSMRA80A01H501U".to_string());
+    }
+
+    #[test]
+    fn test_generate_mixed_case_accents() {
+        let person_data = PersonData::new(
+            "Nicolò".to_string(),
+            "De Luca".to_string(),
+            NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+            Gender::M,
+            "H501".to_string(),
+        )
+        .unwrap();
+
+        let codice_fiscale = CodiceFiscale::generate(&person_data);
+
+        assert_eq!(&codice_fiscale.get()[0..6], "DLCNCL");
+        assert_eq!(codice_fiscale.get(), codice_fiscale.get().to_uppercase());
+    }
+
     #[test]
     fn test_random_generator() {
         let codice_fiscale = CodiceFiscale::generate_random(Some(19));
@@ -195,6 +939,109 @@ mod tests {
         assert_eq!(omocodes.first().unwrap().get(), "ZLKESP25B55Y46PH");
     }
 
+    #[test]
+    fn all_omocodes_from_normal_cf() {
+        let codice_fiscale = CodiceFiscale::new("ZLKESP25B55Y463L").unwrap();
+        let all_omocodes = codice_fiscale.all_omocodes();
+
+        assert_eq!(all_omocodes.len(), 127);
+        assert_eq!(all_omocodes.first().unwrap().get(), "ZLKESP25B55Y46PH");
+        assert!(all_omocodes.iter().all(|omocode| omocode
+            .get()
+            .ends_with(control_code::ControlCode::compute(&omocode.get()))));
+        assert!(codice_fiscale
+            .omocodes()
+            .iter()
+            .all(|omocode| all_omocodes.contains(omocode)));
+    }
+
+    #[test]
+    fn all_omocodes_are_unique() {
+        let mut all_omocodes: Vec<String> = CodiceFiscale::new("ZLKESP25B55Y463L")
+            .unwrap()
+            .all_omocodes()
+            .iter()
+            .map(|omocode| omocode.get())
+            .collect();
+        all_omocodes.sort();
+        all_omocodes.dedup();
+
+        assert_eq!(all_omocodes.len(), 127);
+    }
+
+    #[test]
+    fn canonical_from_normal_cf() {
+        let codice_fiscale = CodiceFiscale::new("ZLKESP25B55Y463L").unwrap();
+
+        assert_eq!(codice_fiscale.canonical(), codice_fiscale);
+    }
+
+    #[test]
+    fn canonical_from_omocode_cf() {
+        let codice_fiscale = CodiceFiscale::new("BRNPRZ72D52F83VC").unwrap();
+
+        assert_eq!(codice_fiscale.canonical().get(), "BRNPRZ72D52F839N");
+        assert!(CodiceFiscale::new(&codice_fiscale.canonical().get()).is_ok());
+    }
+
+    #[test]
+    fn canonical_from_lowercase_cf() {
+        let codice_fiscale = CodiceFiscale::new("cTMTBT74E05B506W").unwrap();
+
+        assert_eq!(codice_fiscale.canonical().get(), "CTMTBT74E05B506W");
+    }
+
+    #[test]
+    fn is_omocode_of() {
+        let codice_fiscale = CodiceFiscale::new("ZLKESP25B55Y463L").unwrap();
+
+        assert!(codice_fiscale
+            .omocodes()
+            .iter()
+            .all(|omocode| omocode.is_omocode_of(&codice_fiscale)
+                && codice_fiscale.is_omocode_of(omocode)));
+        assert!(!codice_fiscale.is_omocode_of(&CodiceFiscale::new("BRNPRZ72D52F83VC").unwrap()));
+    }
+
+    #[test]
+    fn canonical_from_non_contiguous_omocode_cf() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRAT0A01H5L1A").unwrap();
+
+        assert!(codice_fiscale.is_omocode());
+        assert_eq!(codice_fiscale.canonical().get(), "RSSMRA70A01H501S");
+    }
+
+    #[test]
+    fn omocode_level_and_positions() {
+        let codice_fiscale = CodiceFiscale::new("ZLKESP25B55Y463L").unwrap();
+
+        assert_eq!(codice_fiscale.omocode_level(), 0);
+        assert_eq!(codice_fiscale.omocode_positions(), vec![]);
+
+        for (level, omocode) in codice_fiscale.omocodes().iter().enumerate() {
+            assert_eq!(omocode.omocode_level(), level + 1);
+        }
+
+        let omocode = CodiceFiscale::new("RSSMRAT0A01H5L1A").unwrap();
+        assert_eq!(omocode.omocode_level(), 2);
+        assert_eq!(omocode.omocode_positions(), vec![6, 13]);
+    }
+
+    #[test]
+    fn omocode_at_level() {
+        let codice_fiscale = CodiceFiscale::new("ZLKESP25B55Y463L").unwrap();
+        let omocodes = codice_fiscale.omocodes();
+
+        assert_eq!(codice_fiscale.omocode(0), Ok(codice_fiscale.canonical()));
+        assert_eq!(codice_fiscale.omocode(1).as_ref(), Ok(&omocodes[0]));
+        assert_eq!(codice_fiscale.omocode(7).as_ref(), Ok(&omocodes[6]));
+        assert_eq!(omocodes[3].omocode(2).as_ref(), Ok(&omocodes[1]));
+        assert_eq!(
+            codice_fiscale.omocode(8),
+            Err(OmocodeError::InvalidLevel(8))
+        );
+    }
+
     #[test]
     fn omocodes_from_omocode_cf() {
         let omocodes = CodiceFiscale::new("BRNPRZ72D52F83VC").unwrap().omocodes();
@@ -0,0 +1,230 @@
+//! Comparison of a codice fiscale with the personal data declared by its owner, field by
+//! field, so that the fields that disagree can be told apart.
+
+use crate::{
+    generator::Generator,
+    person_data::PersonData,
+    verifier::{BIRTH_DATE_PART, BIRTH_PLACE_PART, NAME_PART, SURNAME_PART},
+    CodiceFiscale,
+};
+
+/// A field encoded from the declared personal data compared with the same field
+/// found in the codice fiscale, omocode characters mapped back to digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldComparison {
+    expected: String,
+    found: String,
+}
+
+impl FieldComparison {
+    fn new(expected: String, found: &str) -> FieldComparison {
+        FieldComparison {
+            expected,
+            found: found.to_string(),
+        }
+    }
+
+    /// The field encoded from the declared personal data.
+    pub fn expected(&self) -> String {
+        self.expected.to_string()
+    }
+
+    /// The field found in the codice fiscale.
+    pub fn found(&self) -> String {
+        self.found.to_string()
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.expected == self.found
+    }
+}
+
+/// The comparison of each field of a codice fiscale with the declared personal data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchReport {
+    surname: FieldComparison,
+    name: FieldComparison,
+    birthdate: FieldComparison,
+    gender: FieldComparison,
+    birth_place: FieldComparison,
+}
+
+impl MatchReport {
+    /// Compares the codice fiscale with the personal data. The codice fiscale is compared in its
+    /// canonical form, so an omocode matches the personal data of the original codice fiscale.
+    pub(crate) fn new(codice_fiscale: &CodiceFiscale, person_data: &PersonData) -> MatchReport {
+        let canonical = codice_fiscale.canonical().get();
+        let found_gender = codice_fiscale
+            .decode()
+            .map(|decoded| decoded.gender())
+            .unwrap_or(person_data.gender());
+
+        MatchReport {
+            surname: FieldComparison::new(
+                Generator::generate_surname_part(&person_data.surname()),
+                &canonical[SURNAME_PART],
+            ),
+            name: FieldComparison::new(
+                Generator::generate_name_part(&person_data.name()),
+                &canonical[NAME_PART],
+            ),
+            // The declared date is encoded with the gender found in the codice fiscale,
+            // so that a different gender is not reported as a different date too.
+            birthdate: FieldComparison::new(
+                Generator::generate_birth_date_part(person_data.birthdate(), found_gender),
+                &canonical[BIRTH_DATE_PART],
+            ),
+            gender: FieldComparison::new(
+                person_data.gender().to_string(),
+                &found_gender.to_string(),
+            ),
+            birth_place: FieldComparison::new(
+                person_data.birth_place().to_uppercase(),
+                &canonical[BIRTH_PLACE_PART],
+            ),
+        }
+    }
+
+    /// The three letters encoding the surname.
+    pub fn surname(&self) -> &FieldComparison {
+        &self.surname
+    }
+
+    /// The three letters encoding the name.
+    pub fn name(&self) -> &FieldComparison {
+        &self.name
+    }
+
+    /// The last two digits of the birth year, the month code and the birth day. The day is
+    /// increased by 40 if the codice fiscale belongs to a woman.
+    pub fn birthdate(&self) -> &FieldComparison {
+        &self.birthdate
+    }
+
+    /// The gender, `M` or `F`.
+    pub fn gender(&self) -> &FieldComparison {
+        &self.gender
+    }
+
+    /// The codice catastale of the birth place.
+    pub fn birth_place(&self) -> &FieldComparison {
+        &self.birth_place
+    }
+
+    /// Returns true if every field matches.
+    pub fn is_match(&self) -> bool {
+        [
+            &self.surname,
+            &self.name,
+            &self.birthdate,
+            &self.gender,
+            &self.birth_place,
+        ]
+        .iter()
+        .all(|field| field.is_match())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::person_data::Gender;
+
+    fn person_data(name: &str, birthdate: NaiveDate, gender: Gender) -> PersonData {
+        PersonData::new(
+            name.to_string(),
+            "ROSSI".to_string(),
+            birthdate,
+            gender,
+            "H501".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matching_person_data() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+        let report = MatchReport::new(
+            &codice_fiscale,
+            &person_data(
+                "Mario",
+                NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+                Gender::M,
+            ),
+        );
+
+        assert!(report.is_match());
+        assert_eq!(report.birthdate().found(), "80A01");
+    }
+
+    #[test]
+    fn matching_omocode() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRAT0A01H5L1A").unwrap();
+        let report = MatchReport::new(
+            &codice_fiscale,
+            &person_data(
+                "MARIO",
+                NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+                Gender::M,
+            ),
+        );
+
+        assert!(report.is_match());
+    }
+
+    #[test]
+    fn different_name() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+        let report = MatchReport::new(
+            &codice_fiscale,
+            &person_data(
+                "MARCO",
+                NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+                Gender::M,
+            ),
+        );
+
+        assert!(!report.is_match());
+        assert!(!report.name().is_match());
+        assert_eq!(report.name().expected(), "MRC");
+        assert_eq!(report.name().found(), "MRA");
+        assert!(report.surname().is_match());
+        assert!(report.birthdate().is_match());
+    }
+
+    #[test]
+    fn different_gender() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+        let report = MatchReport::new(
+            &codice_fiscale,
+            &person_data(
+                "MARIO",
+                NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+                Gender::F,
+            ),
+        );
+
+        assert!(!report.gender().is_match());
+        assert!(report.birthdate().is_match());
+    }
+
+    #[test]
+    fn different_birthdate() {
+        let codice_fiscale = CodiceFiscale::new("RSSMRA80A01H501U").unwrap();
+        let report = MatchReport::new(
+            &codice_fiscale,
+            &person_data(
+                "MARIO",
+                NaiveDate::from_ymd_opt(1980, 1, 10).unwrap(),
+                Gender::M,
+            ),
+        );
+
+        assert!(!report.birthdate().is_match());
+        assert_eq!(report.birthdate().expected(), "80A10");
+        assert!(report.gender().is_match());
+        assert!(report.birth_place().is_match());
+    }
+}
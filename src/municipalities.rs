@@ -0,0 +1,355 @@
+//! Registry of the Italian municipalities and their codice catastale, also known as Belfiore code.
+//!
+//! The registry is embedded in the crate from `data/municipalities.csv` and it's available
+//! only when the `registry` feature is enabled. The file uses the ISTAT naming of municipalities,
+//! provinces and regions and it's regenerated from the ISTAT "Elenco dei comuni italiani" with
+//! `cargo run --example import_municipalities`, which keeps the suppressed municipalities.
+//! At the moment it contains the provincial capitals and a few other municipalities.
+//!
+//! Municipalities that have been created or suppressed over time carry their validity interval,
+//! so that a birth place can be checked against the birth date.
+//!
+//! Municipalities can also be searched by name, the search tolerates accents, apostrophes,
+//! abbreviations of saints names and matches both the names of bilingual municipalities.
+
+use std::{str::FromStr, sync::OnceLock};
+
+use chrono::NaiveDate;
+use strsim::jaro_winkler;
+
+use crate::common::remove_diacritics;
+
+const MUNICIPALITIES_DATA: &str = include_str!("../data/municipalities.csv");
+
+/// Minimum similarity of a municipality name to be returned by the search.
+const MIN_SIMILARITY: f64 = 0.8;
+
+/// Saints names prefixes and their abbreviations, they are all considered equivalent.
+const SAINT_PREFIXES: &[&str] = &["S", "SAN", "SANT", "SANTA", "SANTO", "STA", "STO"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Municipality {
+    code: &'static str,
+    name: &'static str,
+    province: &'static str,
+    region: &'static str,
+    valid_from: Option<NaiveDate>,
+    valid_to: Option<NaiveDate>,
+}
+
+impl Municipality {
+    /// Returns the municipality identified by the provided codice catastale, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::municipalities::Municipality;
+    ///
+    /// let municipality = Municipality::from_code("H501").unwrap();
+    /// assert_eq!(municipality.name(), "Roma");
+    /// assert_eq!(municipality.province(), "RM");
+    /// assert!(Municipality::from_code("A000").is_none());
+    /// ```
+    pub fn from_code(code: &str) -> Option<&'static Municipality> {
+        Municipality::all()
+            .iter()
+            .find(|municipality| municipality.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Search the municipalities by name, optionally restricted to a province sigla. The results
+    /// are ranked from the most to the least similar, an exact match has similarity 1, with the
+    /// suppressed municipalities, those with a [`valid_to`](Municipality::valid_to) date, last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::municipalities::Municipality;
+    ///
+    /// let matches = Municipality::search("l'aquila", None);
+    /// assert_eq!(matches[0].municipality().code(), "A345");
+    ///
+    /// let matches = Municipality::search("S. Benedetto del Tronto", Some("AP"));
+    /// assert_eq!(matches[0].municipality().code(), "H769");
+    ///
+    /// let matches = Municipality::search("Bozen", None);
+    /// assert_eq!(matches[0].municipality().code(), "A952");
+    /// ```
+    pub fn search(name: &str, province: Option<&str>) -> Vec<MunicipalityMatch> {
+        // Bilingual names are written with both the forms separated by a slash, as in the
+        // registry: each form of the query is compared with each form of the name.
+        let queries: Vec<String> = name
+            .split('/')
+            .map(normalize_name)
+            .filter(|query| !query.is_empty())
+            .collect();
+        if queries.is_empty() {
+            return vec![];
+        }
+
+        let mut matches: Vec<MunicipalityMatch> = Municipality::all()
+            .iter()
+            .filter(|municipality| {
+                province.is_none_or(|province| {
+                    municipality.province.eq_ignore_ascii_case(province.trim())
+                })
+            })
+            .filter_map(|municipality| {
+                let similarity = municipality
+                    .name
+                    .split('/')
+                    .map(normalize_name)
+                    .flat_map(|name| queries.iter().map(move |query| jaro_winkler(query, &name)))
+                    .fold(0.0, f64::max);
+
+                (similarity >= MIN_SIMILARITY).then_some(MunicipalityMatch {
+                    municipality,
+                    similarity,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            a.municipality
+                .valid_to
+                .is_some()
+                .cmp(&b.municipality.valid_to.is_some())
+                .then(b.similarity.total_cmp(&a.similarity))
+        });
+        matches
+    }
+
+    /// Returns all the municipalities in the registry.
+    pub fn all() -> &'static [Municipality] {
+        static MUNICIPALITIES: OnceLock<Vec<Municipality>> = OnceLock::new();
+        MUNICIPALITIES.get_or_init(|| {
+            MUNICIPALITIES_DATA
+                .lines()
+                .skip(1)
+                .filter_map(parse_municipality)
+                .collect()
+        })
+    }
+
+    /// The codice catastale (Belfiore code) of the municipality.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The province sigla, e.g. `RM` for Roma.
+    pub fn province(&self) -> &'static str {
+        self.province
+    }
+
+    pub fn region(&self) -> &'static str {
+        self.region
+    }
+
+    /// The first day the municipality existed, `None` if it predates the registry records.
+    pub fn valid_from(&self) -> Option<NaiveDate> {
+        self.valid_from
+    }
+
+    /// The last day the municipality existed, `None` if it still exists.
+    pub fn valid_to(&self) -> Option<NaiveDate> {
+        self.valid_to
+    }
+
+    /// Returns true if the municipality existed on the provided date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::municipalities::Municipality;
+    /// use chrono::NaiveDate;
+    ///
+    /// let municipality = Municipality::from_code("M403").unwrap();
+    /// assert!(municipality.is_active_on(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()));
+    /// assert!(!municipality.is_active_on(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap()));
+    /// ```
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.valid_from.is_none_or(|valid_from| valid_from <= date)
+            && self.valid_to.is_none_or(|valid_to| date <= valid_to)
+    }
+}
+
+/// A municipality returned by the search together with the similarity of its name,
+/// between 0 and 1, to the searched one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MunicipalityMatch {
+    municipality: &'static Municipality,
+    similarity: f64,
+}
+
+impl MunicipalityMatch {
+    pub fn municipality(&self) -> &'static Municipality {
+        self.municipality
+    }
+
+    pub fn similarity(&self) -> f64 {
+        self.similarity
+    }
+}
+
+/// Normalizes a municipality name for the search: diacritics are removed, punctuation is
+/// replaced by spaces and all the saints names prefixes are mapped to the same token.
+fn normalize_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(remove_diacritics)
+        .map(|char| match char.is_ascii_alphanumeric() {
+            true => char.to_ascii_uppercase(),
+            false => ' ',
+        })
+        .collect();
+
+    name.split_whitespace()
+        .map(|token| match SAINT_PREFIXES.contains(&token) {
+            true => "S",
+            false => token,
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn parse_municipality(line: &'static str) -> Option<Municipality> {
+    match line.split(';').collect::<Vec<&str>>().as_slice() {
+        &[code, name, province, region, valid_from, valid_to] => Some(Municipality {
+            code,
+            name,
+            province,
+            region,
+            valid_from: parse_date(valid_from)?,
+            valid_to: parse_date(valid_to)?,
+        }),
+        _ => None,
+    }
+}
+
+/// Parses an optional date of the registry, an empty value means the interval is open.
+fn parse_date(value: &str) -> Option<Option<NaiveDate>> {
+    match value {
+        "" => Some(None),
+        value => NaiveDate::from_str(value).ok().map(Some),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_parse_all_lines() {
+        assert_eq!(
+            Municipality::all().len(),
+            MUNICIPALITIES_DATA.lines().count() - 1
+        );
+    }
+
+    #[test]
+    fn registry_from_code() {
+        assert_eq!(
+            Municipality::from_code("f205"),
+            Some(&Municipality {
+                code: "F205",
+                name: "Milano",
+                province: "MI",
+                region: "Lombardia",
+                valid_from: None,
+                valid_to: None,
+            })
+        );
+    }
+
+    #[test]
+    fn registry_suppressed_municipality() {
+        let municipality = Municipality::from_code("D005").unwrap();
+
+        assert!(municipality.is_active_on(NaiveDate::from_ymd_opt(2018, 3, 30).unwrap()));
+        assert!(!municipality.is_active_on(NaiveDate::from_ymd_opt(2018, 3, 31).unwrap()));
+    }
+
+    #[test]
+    fn normalize_municipality_name() {
+        assert_eq!(normalize_name("Reggio nell'Emilia"), "REGGIO NELL EMILIA");
+        assert_eq!(normalize_name("  Forlì "), "FORLI");
+        assert_eq!(normalize_name("Sant'Agata"), "S AGATA");
+        assert_eq!(normalize_name("S.Agata"), "S AGATA");
+    }
+
+    #[test]
+    fn search_exact_name() {
+        let matches = Municipality::search("MILANO", None);
+
+        assert_eq!(matches[0].municipality().code(), "F205");
+        assert_eq!(matches[0].similarity(), 1.0);
+    }
+
+    #[test]
+    fn search_accents() {
+        let matches = Municipality::search("forli", None);
+
+        assert_eq!(matches[0].municipality().code(), "D704");
+        assert_eq!(matches[0].similarity(), 1.0);
+    }
+
+    #[test]
+    fn search_ranked_candidates() {
+        let matches = Municipality::search("Reggio Emilia", None);
+
+        assert_eq!(matches[0].municipality().code(), "H223");
+        assert!(matches
+            .iter()
+            .any(|candidate| candidate.municipality().code() == "H224"));
+    }
+
+    #[test]
+    fn search_province() {
+        let matches = Municipality::search("Reggio", Some("rc"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].municipality().code(), "H224");
+    }
+
+    #[test]
+    fn search_bilingual_name() {
+        let matches = Municipality::search("Bolzano", None);
+
+        assert_eq!(matches[0].municipality().code(), "A952");
+        assert_eq!(matches[0].similarity(), 1.0);
+
+        let matches = Municipality::search("Bolzano/Bozen", None);
+        assert_eq!(matches[0].municipality().code(), "A952");
+        assert_eq!(matches[0].similarity(), 1.0);
+    }
+
+    #[test]
+    fn search_suppressed_last() {
+        let matches = Municipality::search("Corigliano Calabro", None);
+
+        assert_eq!(matches[0].municipality().code(), "M403");
+        assert_eq!(matches[1].municipality().code(), "D005");
+        assert_eq!(matches[1].similarity(), 1.0);
+    }
+
+    #[test]
+    fn search_typo() {
+        let matches = Municipality::search("Torinno", None);
+
+        assert_eq!(matches[0].municipality().code(), "L219");
+    }
+
+    #[test]
+    fn search_no_match() {
+        assert_eq!(Municipality::search("Atlantide", None), vec![]);
+        assert_eq!(Municipality::search(" ' ", None), vec![]);
+    }
+
+    #[test]
+    fn registry_from_code_unknown() {
+        assert_eq!(Municipality::from_code("Z999"), None);
+    }
+}
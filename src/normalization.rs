@@ -0,0 +1,191 @@
+//! Normalization of codici fiscali pasted from documents or forms, where they are often written
+//! in lowercase and split in groups by whitespace or separators, e.g. `rss mra 80a01 h501u`
+//! or `RSS-MRA-80A01-H501U`.
+
+use crate::CodiceFiscale;
+
+/// Characters used to split a codice fiscale in groups, removed together with the whitespace.
+const SEPARATORS: &[char] = &['-', '.', '/', '_'];
+
+/// A change done normalizing a codice fiscale, the position is the byte position of the
+/// character in the provided value, as the positions reported by the verification errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// A whitespace or separator character has been removed.
+    Removed { position: usize, character: char },
+    /// A lowercase ascii letter has been converted to uppercase.
+    Uppercased { position: usize, character: char },
+}
+
+impl Change {
+    /// The byte position of the changed character in the provided value.
+    pub fn position(&self) -> usize {
+        match self {
+            Change::Removed { position, .. } | Change::Uppercased { position, .. } => *position,
+        }
+    }
+}
+
+/// A valid codice fiscale obtained normalizing the provided value, together with the changes done.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Normalized {
+    codice_fiscale: CodiceFiscale,
+    changes: Vec<Change>,
+}
+
+impl Normalized {
+    pub(crate) fn new(codice_fiscale: CodiceFiscale, changes: Vec<Change>) -> Normalized {
+        Normalized {
+            codice_fiscale,
+            changes,
+        }
+    }
+
+    pub fn codice_fiscale(&self) -> &CodiceFiscale {
+        &self.codice_fiscale
+    }
+
+    /// The changes done, in the order of the characters they refer to.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Returns true if the provided value was already normalized.
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl From<Normalized> for CodiceFiscale {
+    fn from(normalized: Normalized) -> Self {
+        normalized.codice_fiscale
+    }
+}
+
+/// Removes whitespace and separators and converts lowercase ascii letters to uppercase,
+/// returning the normalized value and the changes done. Other letters are kept as they are,
+/// since no conversion can make them valid.
+pub(crate) fn normalize(raw_codice_fiscale: &str) -> (String, Vec<Change>) {
+    let mut normalized = String::new();
+    let mut changes = vec![];
+
+    for (position, character) in raw_codice_fiscale.char_indices() {
+        if character.is_whitespace() || SEPARATORS.contains(&character) {
+            changes.push(Change::Removed {
+                position,
+                character,
+            });
+        } else if character.is_ascii_lowercase() {
+            changes.push(Change::Uppercased {
+                position,
+                character,
+            });
+            normalized.push(character.to_ascii_uppercase());
+        } else {
+            normalized.push(character);
+        }
+    }
+
+    (normalized, changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_normalized_value() {
+        assert_eq!(
+            normalize("RSSMRA80A01H501U"),
+            ("RSSMRA80A01H501U".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn normalize_whitespace_and_lowercase() {
+        let (normalized, changes) = normalize(" rss mra 80a01 h501u ");
+
+        assert_eq!(normalized, "RSSMRA80A01H501U");
+        assert_eq!(changes.len(), 14);
+        assert_eq!(
+            changes[0],
+            Change::Removed {
+                position: 0,
+                character: ' '
+            }
+        );
+        assert_eq!(
+            changes[1],
+            Change::Uppercased {
+                position: 1,
+                character: 'r'
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_separators() {
+        let (normalized, changes) = normalize("RSS-MRA.80A01/H501_U");
+
+        assert_eq!(normalized, "RSSMRA80A01H501U");
+        assert_eq!(
+            changes,
+            vec![
+                Change::Removed {
+                    position: 3,
+                    character: '-'
+                },
+                Change::Removed {
+                    position: 7,
+                    character: '.'
+                },
+                Change::Removed {
+                    position: 13,
+                    character: '/'
+                },
+                Change::Removed {
+                    position: 18,
+                    character: '_'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_byte_positions() {
+        assert_eq!(
+            normalize("é-r").1,
+            vec![
+                Change::Removed {
+                    position: 2,
+                    character: '-'
+                },
+                Change::Uppercased {
+                    position: 3,
+                    character: 'r'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_other_characters() {
+        assert_eq!(normalize("RSS+MRA").0, "RSS+MRA");
+        assert_eq!(
+            normalize("rsßé"),
+            (
+                "RSßé".to_string(),
+                vec![
+                    Change::Uppercased {
+                        position: 0,
+                        character: 'r'
+                    },
+                    Change::Uppercased {
+                        position: 1,
+                        character: 's'
+                    },
+                ]
+            )
+        );
+    }
+}
@@ -1,42 +1,148 @@
 use std::str::Chars;
 
+use crate::errors::{OmocodeError, VerifierError};
+
+type Result<T> = std::result::Result<T, VerifierError>;
+
+/// Positions of the digits that can be replaced by a letter, in substitution order.
+const OMOCODES_LETTER_INDICES: [usize; 7] = [14, 13, 12, 10, 9, 7, 6];
+
+/// Letters replacing the digits from 0 to 9.
+pub const OMOCODES_LETTERS: [char; 10] = ['L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'U', 'V'];
+
 pub struct Omocodes {}
 
 impl Omocodes {
-    #[allow(dead_code)]
+    /// Generate the omocodes replacing the digits one at a time, following the substitution
+    /// order. Positions that are missing or don't hold a digit are left as they are.
+    /// The control character is not updated.
     pub fn generate(codice_fiscale: Chars) -> Vec<Vec<char>> {
-        let mut starting_codice_fiscale: Vec<char> = codice_fiscale.collect::<Vec<char>>().to_vec();
-        let omocodes_letter_indices = [14, 13, 12, 10, 9, 7, 6];
+        let mut starting_codice_fiscale: Vec<char> = codice_fiscale.collect();
         let mut omocodes: Vec<Vec<char>> = vec![];
 
-        for index in omocodes_letter_indices {
-            starting_codice_fiscale[index] =
-                inverse_map_omocodes(starting_codice_fiscale[index]).unwrap();
+        for index in OMOCODES_LETTER_INDICES {
+            if let Some(letter) = starting_codice_fiscale
+                .get(index)
+                .and_then(|c| inverse_map_omocodes(*c))
+            {
+                starting_codice_fiscale[index] = letter;
+            }
             omocodes.push(starting_codice_fiscale.to_vec())
         }
 
         omocodes
     }
 
-    pub fn replace_omocodes_characters(value: &str) -> String {
-        let mut purified_value: Vec<char> = value.chars().collect();
-        let omocodes_letter_indices = [14, 13, 12, 10, 9, 7, 6];
-        for index in omocodes_letter_indices {
-            let current_letter = purified_value[index];
-            if !current_letter.is_ascii_alphabetic() {
-                break;
-            }
+    /// Generate every omocode, one for each non empty subset of the replaceable digits,
+    /// ordered by the number of replaced digits. The control character is not updated.
+    pub fn generate_all(codice_fiscale: Chars) -> Vec<Vec<char>> {
+        let starting_codice_fiscale: Vec<char> = codice_fiscale.collect();
+        let mut subsets: Vec<u32> = (1..1 << OMOCODES_LETTER_INDICES.len()).collect();
+        subsets.sort_by_key(|subset| subset.count_ones());
+
+        subsets
+            .iter()
+            .map(|subset| {
+                let mut omocode = starting_codice_fiscale.to_vec();
+                for (bit, index) in OMOCODES_LETTER_INDICES.iter().enumerate() {
+                    if subset & (1 << bit) == 0 {
+                        continue;
+                    }
+                    if let Some(letter) = omocode.get(*index).and_then(|c| inverse_map_omocodes(*c))
+                    {
+                        omocode[*index] = letter;
+                    }
+                }
+                omocode
+            })
+            .collect()
+    }
+
+    /// Returns the replaceable positions used by the omocode at the provided level,
+    /// following the substitution order.
+    pub fn positions_at_level(level: usize) -> std::result::Result<Vec<usize>, OmocodeError> {
+        OMOCODES_LETTER_INDICES
+            .get(..level)
+            .map(|positions| positions.to_vec())
+            .ok_or(OmocodeError::InvalidLevel(level))
+    }
+
+    /// Replace the digits at the provided positions with their omocode letter, a position
+    /// repeated in the list is replaced once. The control character is not updated.
+    pub fn generate_at(
+        codice_fiscale: Chars,
+        positions: &[usize],
+    ) -> std::result::Result<Vec<char>, OmocodeError> {
+        let mut omocode: Vec<char> = codice_fiscale.collect();
+        let mut positions = positions.to_vec();
+        positions.sort_unstable();
+        positions.dedup();
 
-            let map_current_letter = map_omocodes(current_letter);
-            if map_current_letter.is_none() {
-                return value.to_string();
+        for position in &positions {
+            if !OMOCODES_LETTER_INDICES.contains(position) {
+                return Err(OmocodeError::InvalidPosition(*position));
             }
+            match omocode
+                .get(*position)
+                .and_then(|c| inverse_map_omocodes(*c))
+            {
+                Some(letter) => omocode[*position] = letter,
+                None => return Err(OmocodeError::InvalidPosition(*position)),
+            }
+        }
+
+        Ok(omocode)
+    }
+
+    /// Map the omocode letters back to digits, at any subset of the replaceable positions.
+    /// Returns the purified value together with the positions that carried an omocode letter,
+    /// in ascending order.
+    pub fn try_replace_omocodes_characters(value: &str) -> Result<(String, Vec<usize>)> {
+        let (purified_value, omocode_positions, errors) =
+            Omocodes::partially_replace_omocodes_characters(value);
+
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok((purified_value, omocode_positions)),
+        }
+    }
+
+    /// Map the omocode letters back to digits, leaving in place the letters that are not
+    /// omocode letters. Returns the purified value, the positions that carried an omocode
+    /// letter and an error for each invalid letter, both in ascending order.
+    pub fn partially_replace_omocodes_characters(
+        value: &str,
+    ) -> (String, Vec<usize>, Vec<VerifierError>) {
+        let mut purified_value: Vec<char> = value.chars().collect();
+        let mut omocode_positions = vec![];
+        let mut errors = vec![];
+
+        for index in OMOCODES_LETTER_INDICES.into_iter().rev() {
+            let current_letter = match purified_value.get(index) {
+                Some(letter) if letter.is_ascii_alphabetic() => *letter,
+                _ => continue,
+            };
 
-            purified_value[index] =
-                map_current_letter.expect("mapping shoud be always populated here");
+            match map_omocodes(current_letter) {
+                Some(digit) => {
+                    purified_value[index] = digit;
+                    omocode_positions.push(index);
+                }
+                None => errors.push(VerifierError::InvalidOmocodeCharacter(
+                    current_letter,
+                    index,
+                )),
+            }
         }
 
-        return purified_value.iter().collect();
+        (purified_value.iter().collect(), omocode_positions, errors)
+    }
+
+    pub fn replace_omocodes_characters(value: &str) -> String {
+        match Omocodes::try_replace_omocodes_characters(value) {
+            Ok((purified_value, _)) => purified_value,
+            Err(_) => value.to_string(),
+        }
     }
 }
 
@@ -71,3 +177,102 @@ fn inverse_map_omocodes(letter: char) -> Option<char> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_omocodes_characters_contiguous() {
+        assert_eq!(
+            Omocodes::try_replace_omocodes_characters("BRNPRZ72D52F83VC"),
+            Ok(("BRNPRZ72D52F839C".to_string(), vec![14]))
+        );
+    }
+
+    #[test]
+    fn generate_short_value() {
+        let omocodes = Omocodes::generate("RSSMRA70A".chars());
+
+        assert_eq!(omocodes.len(), 7);
+        assert_eq!(omocodes.last(), Some(&"RSSMRATLA".chars().collect()));
+    }
+
+    #[test]
+    fn generate_at_positions() {
+        assert_eq!(
+            Omocodes::generate_at("RSSMRA70A01H501U".chars(), &[6, 13]),
+            Ok("RSSMRAT0A01H5L1U".chars().collect())
+        );
+        assert_eq!(
+            Omocodes::generate_at("RSSMRA70A01H501U".chars(), &[8]),
+            Err(OmocodeError::InvalidPosition(8))
+        );
+        assert_eq!(
+            Omocodes::generate_at("RSSMRAT0A01H501U".chars(), &[6]),
+            Err(OmocodeError::InvalidPosition(6))
+        );
+    }
+
+    #[test]
+    fn generate_at_repeated_positions() {
+        assert_eq!(
+            Omocodes::generate_at("RSSMRA70A01H501U".chars(), &[6, 6]),
+            Ok("RSSMRAT0A01H501U".chars().collect())
+        );
+        assert_eq!(
+            Omocodes::generate_at("RSSMRA70A01H501U".chars(), &[13, 6, 13]),
+            Omocodes::generate_at("RSSMRA70A01H501U".chars(), &[6, 13])
+        );
+    }
+
+    #[test]
+    fn positions_at_level() {
+        assert_eq!(Omocodes::positions_at_level(0), Ok(vec![]));
+        assert_eq!(Omocodes::positions_at_level(3), Ok(vec![14, 13, 12]));
+        assert_eq!(
+            Omocodes::positions_at_level(8),
+            Err(OmocodeError::InvalidLevel(8))
+        );
+    }
+
+    #[test]
+    fn replace_omocodes_characters_non_contiguous() {
+        assert_eq!(
+            Omocodes::try_replace_omocodes_characters("RSSMRAT0A01H5L1U"),
+            Ok(("RSSMRA70A01H501U".to_string(), vec![6, 13]))
+        );
+    }
+
+    #[test]
+    fn replace_omocodes_characters_invalid_letter() {
+        assert_eq!(
+            Omocodes::try_replace_omocodes_characters("RSSMRAA0A01H501U"),
+            Err(VerifierError::InvalidOmocodeCharacter('A', 6))
+        );
+        assert_eq!(
+            Omocodes::replace_omocodes_characters("RSSMRAA0A01H501U"),
+            "RSSMRAA0A01H501U"
+        );
+    }
+
+    #[test]
+    fn partially_replace_omocodes_characters_invalid_letters() {
+        assert_eq!(
+            Omocodes::partially_replace_omocodes_characters("RSSMRAA0A01H5L1U"),
+            (
+                "RSSMRAA0A01H501U".to_string(),
+                vec![13],
+                vec![VerifierError::InvalidOmocodeCharacter('A', 6)]
+            )
+        );
+    }
+
+    #[test]
+    fn replace_omocodes_characters_short_value() {
+        assert_eq!(
+            Omocodes::try_replace_omocodes_characters("RSSMRAT"),
+            Ok(("RSSMRA7".to_string(), vec![6]))
+        );
+    }
+}
@@ -3,10 +3,13 @@ use std::fmt;
 use chrono::NaiveDate;
 
 use crate::{
-    errors::PersonDataError,
-    verifier::{verify_ascii_alphanumeric, verify_birth_place_part},
+    common::normalize_name_or_surname, errors::PersonDataError,
+    transliteration::TransliterationTable, verifier::verify_birth_place_part,
 };
 
+#[cfg(feature = "registry")]
+use crate::{countries::Country, municipalities::Municipality};
+
 type Result<T> = std::result::Result<T, PersonDataError>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -47,6 +50,14 @@ impl fmt::Display for Gender {
 }
 
 impl PersonData {
+    /// Create a new PersonData. Name and surname can contain accented letters, apostrophes,
+    /// spaces and hyphens, they are normalized following the Circolare 34/E when the codice
+    /// fiscale is generated.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the name or surname contain other characters
+    /// or the birth place is not shaped as a codice catastale.
     pub fn new(
         name: String,
         surname: String,
@@ -54,11 +65,11 @@ impl PersonData {
         gender: Gender,
         place_of_birth: String,
     ) -> Result<PersonData> {
-        if verify_ascii_alphanumeric(&name).is_err() {
+        if !is_ascii_alphabetic(&normalize_name_or_surname(&name)) {
             return Err(PersonDataError::InvalidName());
         }
 
-        if verify_ascii_alphanumeric(&surname).is_err() {
+        if !is_ascii_alphabetic(&normalize_name_or_surname(&surname)) {
             return Err(PersonDataError::InvalidSurname());
         }
 
@@ -75,6 +86,129 @@ impl PersonData {
         })
     }
 
+    /// Create a new PersonData for someone born abroad, the birth place is the codice
+    /// catastale of the country identified by the ISO 3166-1 alpha-2 or alpha-3 code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::person_data::{Gender, PersonData};
+    /// use chrono::NaiveDate;
+    ///
+    /// let person_data = PersonData::new_born_abroad(
+    ///     "GIOVANNI".to_string(),
+    ///     "CONTI".to_string(),
+    ///     NaiveDate::from_ymd_opt(2010, 10, 2).unwrap(),
+    ///     Gender::F,
+    ///     "FR",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(person_data.birth_place(), "Z110");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the country is not in the registry
+    /// or the name or surname are not valid.
+    #[cfg(feature = "registry")]
+    pub fn new_born_abroad(
+        name: String,
+        surname: String,
+        birthdate: NaiveDate,
+        gender: Gender,
+        iso_country_code: &str,
+    ) -> Result<PersonData> {
+        let country =
+            Country::from_iso(iso_country_code).ok_or(PersonDataError::InvalidCountry())?;
+        PersonData::new(name, surname, birthdate, gender, country.code().to_string())
+    }
+
+    /// Create a new PersonData for someone born in the provided municipality, usually
+    /// resolved from its name with [`Municipality::search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::municipalities::Municipality;
+    /// use codice_fiscale_rs::person_data::{Gender, PersonData};
+    /// use chrono::NaiveDate;
+    ///
+    /// let matches = Municipality::search("Roma", None);
+    /// let person_data = PersonData::new_born_in_municipality(
+    ///     "MARIO".to_string(),
+    ///     "ROSSI".to_string(),
+    ///     NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+    ///     Gender::M,
+    ///     matches[0].municipality(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(person_data.birth_place(), "H501");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the name or surname are not valid.
+    #[cfg(feature = "registry")]
+    pub fn new_born_in_municipality(
+        name: String,
+        surname: String,
+        birthdate: NaiveDate,
+        gender: Gender,
+        municipality: &Municipality,
+    ) -> Result<PersonData> {
+        PersonData::new(
+            name,
+            surname,
+            birthdate,
+            gender,
+            municipality.code().to_string(),
+        )
+    }
+
+    /// Create a new PersonData transliterating name and surname to the latin alphabet with the
+    /// provided table. The transliterated values are the ones returned by `name` and `surname`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::person_data::{Gender, PersonData};
+    /// use codice_fiscale_rs::transliteration::TransliterationTable;
+    /// use chrono::NaiveDate;
+    ///
+    /// let person_data = PersonData::new_transliterated(
+    ///     "Юлия".to_string(),
+    ///     "Иванова".to_string(),
+    ///     NaiveDate::from_ymd_opt(1990, 3, 8).unwrap(),
+    ///     Gender::F,
+    ///     "Z154".to_string(),
+    ///     &TransliterationTable::default(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(person_data.name(), "IULIIA");
+    /// assert_eq!(person_data.surname(), "IVANOVA");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the transliterated name or surname are not valid
+    /// or the birth place is not shaped as a codice catastale.
+    pub fn new_transliterated(
+        name: String,
+        surname: String,
+        birthdate: NaiveDate,
+        gender: Gender,
+        place_of_birth: String,
+        table: &TransliterationTable,
+    ) -> Result<PersonData> {
+        PersonData::new(
+            table.transliterate(&name),
+            table.transliterate(&surname),
+            birthdate,
+            gender,
+            place_of_birth,
+        )
+    }
+
     pub fn name(&self) -> String {
         self.name.to_string()
     }
@@ -96,6 +230,10 @@ impl PersonData {
     }
 }
 
+fn is_ascii_alphabetic(value: &str) -> bool {
+    value.chars().all(|letter| letter.is_ascii_alphabetic())
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
@@ -104,7 +242,7 @@ mod tests {
 
     #[test]
     fn person_data_display() {
-        let naive_date = NaiveDate::from_ymd_opt(2023, 01, 08).unwrap();
+        let naive_date = NaiveDate::from_ymd_opt(2023, 1, 8).unwrap();
         let person_data = PersonData::new(
             "PIPPO".to_string(),
             "PLUTO".to_string(),
@@ -144,10 +282,56 @@ mod tests {
     }
 
     #[test]
-    fn person_data_invalid_name() {
+    fn person_data_special_characters() {
         let naive_now = Utc::now().date_naive();
         let person_data = PersonData::new(
             "PòPPO".to_string(),
+            "D'ANGELO-DE LUCA".to_string(),
+            naive_now,
+            Gender::F,
+            "T567".to_string(),
+        );
+
+        assert!(person_data.is_ok());
+    }
+
+    #[test]
+    fn person_data_transliterated() {
+        let naive_now = Utc::now().date_naive();
+        let person_data = PersonData::new_transliterated(
+            "Łukasz".to_string(),
+            "Müller".to_string(),
+            naive_now,
+            Gender::M,
+            "Z127".to_string(),
+            &TransliterationTable::default(),
+        )
+        .unwrap();
+
+        assert_eq!(person_data.name(), "LUKASZ");
+        assert_eq!(person_data.surname(), "MULLER");
+    }
+
+    #[test]
+    fn person_data_transliterated_unknown_letter() {
+        let naive_now = Utc::now().date_naive();
+        let person_data = PersonData::new_transliterated(
+            "李".to_string(),
+            "PLUTO".to_string(),
+            naive_now,
+            Gender::M,
+            "Z210".to_string(),
+            &TransliterationTable::default(),
+        );
+
+        assert_eq!(person_data, Err(PersonDataError::InvalidName()));
+    }
+
+    #[test]
+    fn person_data_invalid_name() {
+        let naive_now = Utc::now().date_naive();
+        let person_data = PersonData::new(
+            "P@PPO".to_string(),
             "PLUTO".to_string(),
             naive_now,
             Gender::F,
@@ -157,6 +341,20 @@ mod tests {
         assert_eq!(person_data, Err(PersonDataError::InvalidName()));
     }
 
+    #[test]
+    fn person_data_name_with_digits() {
+        let naive_now = Utc::now().date_naive();
+        let person_data = PersonData::new(
+            "PIPPO".to_string(),
+            "PLUT0".to_string(),
+            naive_now,
+            Gender::F,
+            "T567".to_string(),
+        );
+
+        assert_eq!(person_data, Err(PersonDataError::InvalidSurname()));
+    }
+
     #[test]
     fn person_data_invalid_surname() {
         let naive_now = Utc::now().date_naive();
@@ -171,6 +369,36 @@ mod tests {
         assert_eq!(person_data, Err(PersonDataError::InvalidSurname()));
     }
 
+    #[cfg(feature = "registry")]
+    #[test]
+    fn person_data_born_abroad() {
+        let naive_now = Utc::now().date_naive();
+        let person_data = PersonData::new_born_abroad(
+            "PIPPO".to_string(),
+            "PLUTO".to_string(),
+            naive_now,
+            Gender::F,
+            "DEU",
+        );
+
+        assert_eq!(person_data.unwrap().birth_place(), "Z112");
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn person_data_born_abroad_invalid_country() {
+        let naive_now = Utc::now().date_naive();
+        let person_data = PersonData::new_born_abroad(
+            "PIPPO".to_string(),
+            "PLUTO".to_string(),
+            naive_now,
+            Gender::F,
+            "XX",
+        );
+
+        assert_eq!(person_data, Err(PersonDataError::InvalidCountry()));
+    }
+
     #[test]
     fn person_data_invalid_birth_place() {
         let naive_now = Utc::now().date_naive();
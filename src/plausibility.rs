@@ -0,0 +1,238 @@
+//! Plausibility checks of the birth date encoded in a valid codice fiscale, relative to the
+//! current date: the birth date must not be in the future and must not imply an age over
+//! a maximum. They are not part of the verification, since a codice fiscale with an implausible
+//! birth date can still be formally correct.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    century_policy::{age, CenturyPolicy},
+    clock::Clock,
+    decoder::DecodedCodiceFiscale,
+    errors::VerifierError,
+};
+
+/// Maximum age used by default, a few years over the oldest person ever recorded.
+const DEFAULT_MAX_AGE: u32 = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plausibility {
+    max_age: u32,
+    century_policy: Option<CenturyPolicy>,
+}
+
+impl Default for Plausibility {
+    fn default() -> Self {
+        Plausibility::new(DEFAULT_MAX_AGE)
+    }
+}
+
+impl Plausibility {
+    /// Create the checks with the provided maximum age. The century of the birth date is
+    /// resolved to the most recent date not in the future, so only the age can be implausible.
+    pub fn new(max_age: u32) -> Plausibility {
+        Plausibility {
+            max_age,
+            century_policy: None,
+        }
+    }
+
+    /// Resolve the century of the birth date with the provided policy instead, then the birth
+    /// date can also be in the future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::plausibility::Plausibility;
+    /// use codice_fiscale_rs::century_policy::CenturyPolicy;
+    ///
+    /// let plausibility = Plausibility::new(100).with_century_policy(CenturyPolicy::Pivot(30));
+    /// assert_eq!(plausibility.century_policy(), Some(CenturyPolicy::Pivot(30)));
+    /// ```
+    pub fn with_century_policy(mut self, century_policy: CenturyPolicy) -> Plausibility {
+        self.century_policy = Some(century_policy);
+        self
+    }
+
+    pub fn max_age(&self) -> u32 {
+        self.max_age
+    }
+
+    pub fn century_policy(&self) -> Option<CenturyPolicy> {
+        self.century_policy
+    }
+
+    /// Returns the birth date if it's plausible on the date returned by the clock.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the birth date doesn't exist in any century, it's
+    /// in the future or it implies an age over the maximum, either the one of the checks or the
+    /// one of the century policy. A date rejected by the policy is in the future when it's after
+    /// its reference date.
+    pub fn check(
+        &self,
+        decoded: &DecodedCodiceFiscale,
+        clock: &impl Clock,
+    ) -> Result<NaiveDate, VerifierError> {
+        let today = clock.today();
+        let century_policy = self
+            .century_policy
+            .unwrap_or(CenturyPolicy::RelativeTo(today));
+
+        let birthdate = match (decoded.birthdate(&century_policy), century_policy) {
+            (Some(birthdate), _) => birthdate,
+            (None, CenturyPolicy::MaxAge { reference, max_age }) => {
+                return Err(birth_date_out_of_policy(decoded, reference, max_age))
+            }
+            (None, _) => return Err(invalid_birth_date(decoded)),
+        };
+
+        if birthdate > today {
+            return Err(VerifierError::FutureBirthDate(birthdate, today));
+        }
+
+        let age = age(&birthdate, &today);
+        if age > self.max_age {
+            return Err(VerifierError::ImplausibleAge(birthdate, age, self.max_age));
+        }
+
+        Ok(birthdate)
+    }
+}
+
+/// Explains why a `MaxAge` policy resolves no birth date: the most recent date of the last
+/// century implies an age over the maximum, or else the only real date is after the reference.
+fn birth_date_out_of_policy(
+    decoded: &DecodedCodiceFiscale,
+    reference: NaiveDate,
+    max_age: u32,
+) -> VerifierError {
+    let (year, month, day) = (
+        decoded.birth_year(),
+        decoded.birth_month(),
+        decoded.birth_day(),
+    );
+
+    let last_century = CenturyPolicy::RelativeTo(reference)
+        .resolve(year, month, day)
+        .filter(|birthdate| age(birthdate, &reference) < 100);
+    if let Some(birthdate) = last_century {
+        return VerifierError::ImplausibleAge(birthdate, age(&birthdate, &reference), max_age);
+    }
+
+    let reference_century = reference.year() - reference.year().rem_euclid(100);
+    [reference_century, reference_century + 100]
+        .into_iter()
+        .filter_map(|century| NaiveDate::from_ymd_opt(century + year as i32, month, day))
+        .find(|birthdate| *birthdate > reference)
+        .map(|birthdate| VerifierError::FutureBirthDate(birthdate, reference))
+        .unwrap_or_else(|| invalid_birth_date(decoded))
+}
+
+fn invalid_birth_date(decoded: &DecodedCodiceFiscale) -> VerifierError {
+    VerifierError::InvalidBirthDate(
+        decoded.birth_year(),
+        decoded.birth_month(),
+        decoded.birth_day(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clock::FixedClock, verifier::Verifier};
+
+    fn clock() -> FixedClock {
+        FixedClock(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+    }
+
+    #[test]
+    fn plausible_birth_date() {
+        let decoded = Verifier::decode("RSSMRA80A01H501U").unwrap();
+
+        assert_eq!(
+            Plausibility::default().check(&decoded, &clock()),
+            Ok(NaiveDate::from_ymd_opt(1980, 1, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn most_recent_birth_date() {
+        let decoded = Verifier::decode("CTMTBT96B69B506H").unwrap();
+
+        assert_eq!(
+            Plausibility::default().check(&decoded, &clock()),
+            Ok(NaiveDate::from_ymd_opt(1996, 2, 29).unwrap())
+        );
+    }
+
+    #[test]
+    fn implausible_age() {
+        let decoded = Verifier::decode("RSSMRA80A01H501U").unwrap();
+
+        assert_eq!(
+            Plausibility::new(40).check(&decoded, &clock()),
+            Err(VerifierError::ImplausibleAge(
+                NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
+                43,
+                40
+            ))
+        );
+    }
+
+    #[test]
+    fn future_birth_date() {
+        let decoded = Verifier::decode("CTMTBT74E05B506W").unwrap();
+        let plausibility = Plausibility::default().with_century_policy(CenturyPolicy::Pivot(80));
+
+        assert_eq!(
+            plausibility.check(&decoded, &clock()),
+            Err(VerifierError::FutureBirthDate(
+                NaiveDate::from_ymd_opt(2074, 5, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn birth_date_not_in_resolved_century() {
+        let decoded = Verifier::decode("CTMTBT00B29B506D").unwrap();
+        let plausibility = Plausibility::default().with_century_policy(CenturyPolicy::Pivot(0));
+
+        assert_eq!(
+            plausibility.check(&decoded, &clock()),
+            Ok(NaiveDate::from_ymd_opt(2000, 2, 29).unwrap())
+        );
+
+        let plausibility = Plausibility::default().with_century_policy(CenturyPolicy::MaxAge {
+            reference: NaiveDate::from_ymd_opt(1999, 12, 31).unwrap(),
+            max_age: 120,
+        });
+        assert_eq!(
+            plausibility.check(&decoded, &clock()),
+            Err(VerifierError::FutureBirthDate(
+                NaiveDate::from_ymd_opt(2000, 2, 29).unwrap(),
+                NaiveDate::from_ymd_opt(1999, 12, 31).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn birth_date_over_century_policy_max_age() {
+        let decoded = Verifier::decode("BLTMHL77S04E889G").unwrap();
+        let plausibility = Plausibility::default().with_century_policy(CenturyPolicy::MaxAge {
+            reference: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            max_age: 18,
+        });
+
+        assert_eq!(
+            plausibility.check(&decoded, &clock()),
+            Err(VerifierError::ImplausibleAge(
+                NaiveDate::from_ymd_opt(1977, 11, 4).unwrap(),
+                45,
+                18
+            ))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::Range;
+
+use crate::verifier::{
+    BIRTH_DAY_AND_GENDER_PART, BIRTH_MONTH_PART, BIRTH_PLACE_PART, BIRTH_YEAR_PART,
+    CONTROL_CHARACTER_PART, NAME_PART, SURNAME_PART,
+};
+
+/// The parts a codice fiscale is made of, in the order they appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Segment {
+    Surname,
+    Name,
+    BirthYear,
+    BirthMonth,
+    BirthDayAndGender,
+    BirthPlace,
+    ControlCharacter,
+}
+
+impl Segment {
+    /// Returns all the segments, in the order they appear.
+    pub fn all() -> [Segment; 7] {
+        [
+            Segment::Surname,
+            Segment::Name,
+            Segment::BirthYear,
+            Segment::BirthMonth,
+            Segment::BirthDayAndGender,
+            Segment::BirthPlace,
+            Segment::ControlCharacter,
+        ]
+    }
+
+    /// Returns the segment the character at the provided position belongs to,
+    /// `None` if the position is out of the 16 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::segment::Segment;
+    ///
+    /// assert_eq!(Segment::at(8), Some(Segment::BirthMonth));
+    /// assert_eq!(Segment::at(16), None);
+    /// ```
+    pub fn at(position: usize) -> Option<Segment> {
+        Segment::all()
+            .into_iter()
+            .find(|segment| segment.range().contains(&position))
+    }
+
+    /// The positions of the characters of the segment within the codice fiscale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::segment::Segment;
+    ///
+    /// assert_eq!(Segment::BirthPlace.range(), 11..15);
+    /// ```
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            Segment::Surname => SURNAME_PART,
+            Segment::Name => NAME_PART,
+            Segment::BirthYear => BIRTH_YEAR_PART,
+            Segment::BirthMonth => BIRTH_MONTH_PART,
+            Segment::BirthDayAndGender => BIRTH_DAY_AND_GENDER_PART,
+            Segment::BirthPlace => BIRTH_PLACE_PART,
+            Segment::ControlCharacter => CONTROL_CHARACTER_PART,
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Correction suggestions for codici fiscali containing a single typo, either a wrong
+//! character or two adjacent characters swapped. The control character makes most of the
+//! possible corrections invalid, the remaining ones are ranked by how likely the typo is.
+
+use crate::{
+    verifier::{Verifier, CONTROL_CHARACTER_PART},
+    CodiceFiscale,
+};
+
+/// Characters that are easily confused with each other, reading a handwritten
+/// or a scanned form.
+const CONFUSABLE_CHARACTERS: &[(char, char)] = &[
+    ('O', '0'),
+    ('Q', '0'),
+    ('D', '0'),
+    ('I', '1'),
+    ('L', '1'),
+    ('Z', '2'),
+    ('E', '3'),
+    ('A', '4'),
+    ('S', '5'),
+    ('G', '6'),
+    ('T', '7'),
+    ('B', '8'),
+    ('G', '9'),
+    ('O', 'Q'),
+    ('O', 'D'),
+    ('U', 'V'),
+    ('M', 'N'),
+    ('C', 'G'),
+    ('E', 'F'),
+    ('P', 'R'),
+    ('I', 'L'),
+];
+
+/// A single typo fixed by a suggestion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Correction {
+    /// The character `found` at `position` has been replaced by `replacement`.
+    Substitution {
+        position: usize,
+        found: char,
+        replacement: char,
+    },
+    /// The characters at `position` and `position + 1` have been swapped.
+    Transposition { position: usize },
+}
+
+impl Correction {
+    /// The position of the first character changed by the correction.
+    pub fn position(&self) -> usize {
+        match self {
+            Correction::Substitution { position, .. } | Correction::Transposition { position } => {
+                *position
+            }
+        }
+    }
+
+    /// Returns true if the correction replaces a character with one that looks alike,
+    /// including the same letter with a different case.
+    pub fn is_confusable(&self) -> bool {
+        match self {
+            Correction::Substitution {
+                found, replacement, ..
+            } => {
+                let found = found.to_ascii_uppercase();
+                found == *replacement
+                    || CONFUSABLE_CHARACTERS.iter().any(|(a, b)| {
+                        (*a, *b) == (found, *replacement) || (*b, *a) == (found, *replacement)
+                    })
+            }
+            Correction::Transposition { .. } => false,
+        }
+    }
+
+    /// Lower is more likely: confusable characters first, then a wrong control character,
+    /// which is the one a typo anywhere else is detected by, then swapped characters and any
+    /// other substitution at last.
+    fn rank(&self) -> u8 {
+        match self {
+            correction if correction.is_confusable() => 0,
+            Correction::Substitution { position, .. }
+                if CONTROL_CHARACTER_PART.contains(position) =>
+            {
+                1
+            }
+            Correction::Transposition { .. } => 2,
+            Correction::Substitution { .. } => 3,
+        }
+    }
+}
+
+/// A valid codice fiscale at one typo of distance from the provided one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    codice_fiscale: CodiceFiscale,
+    correction: Correction,
+}
+
+impl Suggestion {
+    pub fn codice_fiscale(&self) -> &CodiceFiscale {
+        &self.codice_fiscale
+    }
+
+    pub fn correction(&self) -> Correction {
+        self.correction
+    }
+}
+
+/// Returns the valid codici fiscali reachable with a single substitution or a single
+/// transposition of adjacent characters, from the most to the least likely.
+/// A valid codice fiscale has no suggestions.
+pub(crate) fn suggest(raw_codice_fiscale: &str) -> Vec<Suggestion> {
+    let characters: Vec<char> = raw_codice_fiscale.chars().collect();
+    if characters.len() != 16 || Verifier::verify(raw_codice_fiscale).is_ok() {
+        return vec![];
+    }
+
+    let mut suggestions = vec![];
+
+    for (position, found) in characters.iter().enumerate() {
+        for replacement in ('A'..='Z').chain('0'..='9') {
+            if replacement == *found {
+                continue;
+            }
+            let mut candidate = characters.to_vec();
+            candidate[position] = replacement;
+            suggestions.extend(suggestion(
+                candidate,
+                Correction::Substitution {
+                    position,
+                    found: *found,
+                    replacement,
+                },
+            ));
+        }
+    }
+
+    for position in 0..characters.len() - 1 {
+        if characters[position] == characters[position + 1] {
+            continue;
+        }
+        let mut candidate = characters.to_vec();
+        candidate.swap(position, position + 1);
+        suggestions.extend(suggestion(
+            candidate,
+            Correction::Transposition { position },
+        ));
+    }
+
+    suggestions.sort_by_key(|suggestion| {
+        (
+            suggestion.correction.rank(),
+            suggestion.correction.position(),
+        )
+    });
+    suggestions
+}
+
+fn suggestion(candidate: Vec<char>, correction: Correction) -> Option<Suggestion> {
+    let candidate: String = candidate.iter().collect();
+    CodiceFiscale::new(&candidate)
+        .ok()
+        .map(|codice_fiscale| Suggestion {
+            codice_fiscale,
+            correction,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_valid_codice_fiscale() {
+        assert_eq!(suggest("RSSMRA80A01H501U"), vec![]);
+    }
+
+    #[test]
+    fn suggest_invalid_length() {
+        assert_eq!(suggest("RSSMRA80A01H501"), vec![]);
+    }
+
+    #[test]
+    fn suggest_confusable_character() {
+        let suggestions = suggest("RSSMRA8OA01H501U");
+
+        assert_eq!(suggestions[0].codice_fiscale().get(), "RSSMRA80A01H501U");
+        assert_eq!(
+            suggestions[0].correction(),
+            Correction::Substitution {
+                position: 7,
+                found: 'O',
+                replacement: '0'
+            }
+        );
+    }
+
+    #[test]
+    fn suggest_ranked_by_likelihood() {
+        let suggestions = suggest("RSSMPA80A01H501U");
+
+        assert_eq!(suggestions[0].codice_fiscale().get(), "RSSMRA80A01H501U");
+        assert!(suggestions[0].correction().is_confusable());
+        assert!(suggestions.len() > 1);
+        assert!(suggestions
+            .windows(2)
+            .all(|pair| pair[0].correction.rank() <= pair[1].correction.rank()));
+        assert!(suggestions
+            .iter()
+            .all(|suggestion| CodiceFiscale::new(&suggestion.codice_fiscale().get()).is_ok()));
+    }
+
+    #[test]
+    fn suggest_control_character_first() {
+        let suggestions = suggest("RSSMRA80A01H501A");
+
+        assert_eq!(suggestions[0].codice_fiscale().get(), "RSSMRA80A01H501U");
+        assert_eq!(
+            suggestions[0].correction(),
+            Correction::Substitution {
+                position: 15,
+                found: 'A',
+                replacement: 'U'
+            }
+        );
+    }
+
+    #[test]
+    fn suggest_transposition() {
+        let suggestions = suggest("RSSMRA08A01H501U");
+
+        assert!(suggestions.iter().any(|suggestion| suggestion.correction()
+            == Correction::Transposition { position: 6 }
+            && suggestion.codice_fiscale().get() == "RSSMRA80A01H501U"));
+    }
+
+    #[test]
+    fn suggest_lowercase_character() {
+        let suggestions = suggest("RSSMRA80A01H501u");
+
+        assert_eq!(suggestions[0].codice_fiscale().get(), "RSSMRA80A01H501U");
+        assert!(suggestions[0].correction().is_confusable());
+    }
+}
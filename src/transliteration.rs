@@ -0,0 +1,294 @@
+//! Transliteration of names and surnames written with non latin letters, used to register
+//! people born abroad before their codice fiscale is generated.
+
+use std::collections::HashMap;
+
+use crate::{common::remove_diacritics, person_data::PersonData, CodiceFiscale};
+
+/// Default transliterations, following the ICAO Doc 9303 tables printed on passports,
+/// which are the documents Agenzia delle Entrate registers foreign names from.
+/// Accented vowels are not listed, they are handled by the Circolare 34/E normalization:
+/// Ä, Ö and Ü become A, O and U, the alternative ICAO allows to AE, OE and UE.
+const DEFAULT_TRANSLITERATIONS: &[(&str, &str)] = &[
+    // Latin letters
+    ("ß", "SS"),
+    ("Æ", "AE"),
+    ("Œ", "OE"),
+    ("Ø", "OE"),
+    ("Å", "AA"),
+    ("Ã", "A"),
+    ("Ą", "A"),
+    ("Õ", "O"),
+    ("Ő", "O"),
+    ("Ű", "U"),
+    ("Ç", "C"),
+    ("Ć", "C"),
+    ("Č", "C"),
+    ("Ď", "D"),
+    ("Đ", "D"),
+    ("Ð", "D"),
+    ("Ę", "E"),
+    ("Ě", "E"),
+    ("Ğ", "G"),
+    ("İ", "I"),
+    ("Ł", "L"),
+    ("Ñ", "N"),
+    ("Ń", "N"),
+    ("Ň", "N"),
+    ("Ř", "R"),
+    ("Ś", "S"),
+    ("Š", "S"),
+    ("Ş", "S"),
+    ("Ș", "S"),
+    ("Ť", "T"),
+    ("Ţ", "T"),
+    ("Ț", "T"),
+    ("Þ", "TH"),
+    ("Ý", "Y"),
+    ("Ÿ", "Y"),
+    ("Ź", "Z"),
+    ("Ż", "Z"),
+    ("Ž", "Z"),
+    // Cyrillic letters
+    ("А", "A"),
+    ("Б", "B"),
+    ("В", "V"),
+    ("Г", "G"),
+    ("Ґ", "G"),
+    ("Д", "D"),
+    ("Е", "E"),
+    ("Ё", "E"),
+    ("Є", "IE"),
+    ("Ж", "ZH"),
+    ("З", "Z"),
+    ("И", "I"),
+    ("І", "I"),
+    ("Ї", "I"),
+    ("Й", "I"),
+    ("К", "K"),
+    ("Л", "L"),
+    ("М", "M"),
+    ("Н", "N"),
+    ("О", "O"),
+    ("П", "P"),
+    ("Р", "R"),
+    ("С", "S"),
+    ("Т", "T"),
+    ("У", "U"),
+    ("Ф", "F"),
+    ("Х", "KH"),
+    ("Ц", "TS"),
+    ("Ч", "CH"),
+    ("Ш", "SH"),
+    ("Щ", "SHCH"),
+    ("Ъ", "IE"),
+    ("Ы", "Y"),
+    ("Ь", ""),
+    ("Э", "E"),
+    ("Ю", "IU"),
+    ("Я", "IA"),
+    // Greek letters
+    ("Α", "A"),
+    ("Ά", "A"),
+    ("Β", "V"),
+    ("Γ", "G"),
+    ("Δ", "D"),
+    ("Ε", "E"),
+    ("Έ", "E"),
+    ("Ζ", "Z"),
+    ("Η", "I"),
+    ("Ή", "I"),
+    ("Θ", "TH"),
+    ("Ι", "I"),
+    ("Ί", "I"),
+    ("Κ", "K"),
+    ("Λ", "L"),
+    ("Μ", "M"),
+    ("Ν", "N"),
+    ("Ξ", "X"),
+    ("Ο", "O"),
+    ("Ό", "O"),
+    ("Π", "P"),
+    ("Ρ", "R"),
+    ("Σ", "S"),
+    ("Τ", "T"),
+    ("Υ", "Y"),
+    ("Ύ", "Y"),
+    ("Φ", "F"),
+    ("Χ", "CH"),
+    ("Ψ", "PS"),
+    ("Ω", "O"),
+    ("Ώ", "O"),
+    ("ΟΥ", "OU"),
+    ("ΟΎ", "OU"),
+];
+
+/// Table mapping letters, or groups of letters, to their latin transliteration. Letters are
+/// looked up in uppercase, so a single entry covers both cases, and the longest group wins.
+/// The default table follows the ICAO Doc 9303.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransliterationTable {
+    transliterations: HashMap<String, String>,
+    longest_group: usize,
+}
+
+impl Default for TransliterationTable {
+    fn default() -> Self {
+        let mut table = TransliterationTable::new();
+        for (letters, transliteration) in DEFAULT_TRANSLITERATIONS {
+            table.insert(letters, transliteration);
+        }
+        table
+    }
+}
+
+impl TransliterationTable {
+    /// Create an empty table, use [`TransliterationTable::default`] for the default one.
+    pub fn new() -> TransliterationTable {
+        TransliterationTable {
+            transliterations: HashMap::new(),
+            longest_group: 0,
+        }
+    }
+
+    /// Add or replace the transliteration of a letter or a group of letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::transliteration::TransliterationTable;
+    ///
+    /// let mut table = TransliterationTable::default();
+    /// table.insert("Ü", "UE");
+    /// assert_eq!(table.transliterate("Müller"), "MUELLER");
+    /// ```
+    pub fn insert(&mut self, letters: &str, transliteration: &str) {
+        let letters: String = letters.chars().map(to_single_uppercase).collect();
+        self.longest_group = self.longest_group.max(letters.chars().count());
+        self.transliterations
+            .insert(letters, transliteration.to_uppercase());
+    }
+
+    /// Transliterate the provided value, the result is uppercase. Accented letters that are not
+    /// in the table are mapped to their base letter, as the Circolare 34/E normalization does
+    /// before encoding them, the other letters are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use codice_fiscale_rs::transliteration::TransliterationTable;
+    ///
+    /// let table = TransliterationTable::default();
+    /// assert_eq!(table.transliterate("Иванов"), "IVANOV");
+    /// assert_eq!(table.transliterate("Łukasz"), "LUKASZ");
+    /// ```
+    pub fn transliterate(&self, value: &str) -> String {
+        let letters: Vec<char> = value.chars().map(to_single_uppercase).collect();
+        let mut transliterated = String::new();
+        let mut position = 0;
+
+        while position < letters.len() {
+            let longest_group = self.longest_group.min(letters.len() - position);
+            let matched_group = (1..=longest_group).rev().find_map(|group_length| {
+                let group: String = letters[position..position + group_length].iter().collect();
+                self.transliterations
+                    .get(&group)
+                    .map(|transliteration| (group_length, transliteration))
+            });
+
+            match matched_group {
+                Some((group_length, transliteration)) => {
+                    transliterated.push_str(transliteration);
+                    position += group_length;
+                }
+                None => {
+                    transliterated.extend(remove_diacritics(letters[position]).to_uppercase());
+                    position += 1;
+                }
+            }
+        }
+
+        transliterated
+    }
+}
+
+/// Returns the uppercase letter when it's a single character, otherwise the letter itself,
+/// e.g. `ß` is kept as it is since its uppercase is `SS`.
+fn to_single_uppercase(letter: char) -> char {
+    let mut uppercase = letter.to_uppercase();
+    match (uppercase.next(), uppercase.next()) {
+        (Some(uppercase), None) => uppercase,
+        _ => letter,
+    }
+}
+
+/// A codice fiscale generated from transliterated names, together with the transliterated
+/// person data so that the transliteration can be confirmed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Transliteration {
+    pub(crate) person_data: PersonData,
+    pub(crate) codice_fiscale: CodiceFiscale,
+}
+
+impl Transliteration {
+    /// The transliterated name.
+    pub fn name(&self) -> String {
+        self.person_data.name()
+    }
+
+    /// The transliterated surname.
+    pub fn surname(&self) -> String {
+        self.person_data.surname()
+    }
+
+    pub fn person_data(&self) -> &PersonData {
+        &self.person_data
+    }
+
+    pub fn codice_fiscale(&self) -> &CodiceFiscale {
+        &self.codice_fiscale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transliterate_latin_letters() {
+        let table = TransliterationTable::default();
+
+        assert_eq!(table.transliterate("Straße"), "STRASSE");
+        assert_eq!(table.transliterate("Øyvind"), "OEYVIND");
+        assert_eq!(table.transliterate("Ångström"), "AANGSTROM");
+        assert_eq!(table.transliterate("François"), "FRANCOIS");
+        assert_eq!(table.transliterate("Nicolò"), "NICOLO");
+    }
+
+    #[test]
+    fn transliterate_cyrillic_letters() {
+        let table = TransliterationTable::default();
+
+        assert_eq!(table.transliterate("Юлия"), "IULIIA");
+        assert_eq!(table.transliterate("ЩЕРБАКОВ"), "SHCHERBAKOV");
+    }
+
+    #[test]
+    fn transliterate_greek_letters() {
+        let table = TransliterationTable::default();
+
+        assert_eq!(table.transliterate("Παπαδόπουλος"), "PAPADOPOULOS");
+        assert_eq!(table.transliterate("Αθηνά"), "ATHINA");
+    }
+
+    #[test]
+    fn transliterate_custom_table() {
+        let mut table = TransliterationTable::new();
+        table.insert("ж", "j");
+        table.insert("ДЖ", "dj");
+
+        assert_eq!(table.transliterate("жЖ"), "JJ");
+        assert_eq!(table.transliterate("джж"), "DJJ");
+        assert_eq!(table.transliterate("Luca"), "LUCA");
+    }
+}
@@ -1,45 +1,158 @@
-use std::str::FromStr;
+use std::{ops::Range, str::FromStr};
 
 use crate::{
-    common::is_month_code, control_code::ControlCode, errors::VerifierError, omocodes::Omocodes,
+    common::from_month_code,
+    control_code::ControlCode,
+    decoder::DecodedCodiceFiscale,
+    errors::{VerificationIssue, VerifierError},
+    omocodes::Omocodes,
+    person_data::Gender,
+    segment::Segment,
 };
 
+#[cfg(feature = "registry")]
+use crate::{countries::Country, municipalities::Municipality};
+use chrono::NaiveDate;
+
 type Result<T> = std::result::Result<T, VerifierError>;
 
+pub const SURNAME_PART: Range<usize> = 0..3;
+pub const NAME_PART: Range<usize> = 3..6;
+pub const BIRTH_YEAR_PART: Range<usize> = 6..8;
+pub const BIRTH_MONTH_PART: Range<usize> = 8..9;
+pub const BIRTH_DAY_AND_GENDER_PART: Range<usize> = 9..11;
+pub const BIRTH_PLACE_PART: Range<usize> = 11..15;
+pub const CONTROL_CHARACTER_PART: Range<usize> = 15..16;
+/// The birth year, month, day and gender parts taken together.
+pub const BIRTH_DATE_PART: Range<usize> = 6..11;
+
 pub struct Verifier {}
 
 impl Verifier {
     pub fn verify(codice_fiscale: &str) -> Result<()> {
+        Verifier::decode(codice_fiscale)?;
+        Ok(())
+    }
+
+    pub fn decode(codice_fiscale: &str) -> Result<DecodedCodiceFiscale> {
         if codice_fiscale.len() != 16 {
             return Err(VerifierError::InvalidLength(codice_fiscale.len()));
         }
 
         verify_ascii_alphanumeric(codice_fiscale)?;
 
-        let purified_codice_fiscale = Omocodes::replace_omocodes_characters(codice_fiscale);
+        let (purified_codice_fiscale, omocode_positions) =
+            Omocodes::try_replace_omocodes_characters(codice_fiscale)?;
+
+        verify_surname_part(&purified_codice_fiscale[SURNAME_PART])?;
+        verify_name_part(&purified_codice_fiscale[NAME_PART])?;
+        let birth_year = verify_birth_year_part(&purified_codice_fiscale[BIRTH_YEAR_PART])?;
+        let birth_month = verify_birth_month_part(&purified_codice_fiscale[BIRTH_MONTH_PART])?;
+        let (birth_day, gender) =
+            verify_birth_day_and_gender_part(&purified_codice_fiscale[BIRTH_DAY_AND_GENDER_PART])?;
+        verify_birth_date(birth_year, birth_month, birth_day)?;
+        verify_birth_place_part(&purified_codice_fiscale[BIRTH_PLACE_PART])?;
+
+        let control_character = verify_control_code(codice_fiscale)?;
+
+        Ok(DecodedCodiceFiscale {
+            surname: purified_codice_fiscale[SURNAME_PART].to_uppercase(),
+            name: purified_codice_fiscale[NAME_PART].to_uppercase(),
+            birth_year,
+            birth_month,
+            birth_day,
+            gender,
+            birth_place: purified_codice_fiscale[BIRTH_PLACE_PART].to_uppercase(),
+            control_character,
+            omocode_positions,
+        })
+    }
 
-        verify_surname_part(&purified_codice_fiscale[0..3])?;
-        verify_name_part(&purified_codice_fiscale[3..6])?;
-        verify_birth_year_part(&purified_codice_fiscale[6..8])?;
-        verify_birth_month_part(&purified_codice_fiscale[8..9])?;
-        verify_birth_day_and_gender_part(&purified_codice_fiscale[9..11])?;
-        verify_birth_place_part(&purified_codice_fiscale[11..15])?;
+    /// Runs every check instead of stopping at the first failing one. A segment is not
+    /// checked when one of its characters has already been reported as invalid.
+    /// Issues are ordered by position.
+    pub fn verify_all(codice_fiscale: &str) -> Vec<VerificationIssue> {
+        if codice_fiscale.len() != 16 {
+            return vec![VerifierError::InvalidLength(codice_fiscale.len()).into()];
+        }
 
-        verify_control_code(codice_fiscale)?;
+        let mut issues: Vec<VerificationIssue> = codice_fiscale
+            .char_indices()
+            .filter(|(_, char)| !char.is_ascii_alphanumeric())
+            .map(|(position, char)| VerifierError::NonAlphanumericCharacter(char, position).into())
+            .collect();
 
-        Ok(())
+        // Segments can be sliced only when every character is a single byte.
+        if !codice_fiscale.is_ascii() {
+            return issues;
+        }
+
+        let (purified_codice_fiscale, _, omocode_errors) =
+            Omocodes::partially_replace_omocodes_characters(codice_fiscale);
+        issues.extend(omocode_errors.into_iter().map(VerificationIssue::from));
+
+        for segment in Segment::all() {
+            let range = segment.range();
+            if is_reported(&issues, &range) {
+                continue;
+            }
+
+            let outcome = match segment {
+                Segment::Surname => verify_surname_part(&purified_codice_fiscale[range.clone()]),
+                Segment::Name => verify_name_part(&purified_codice_fiscale[range.clone()]),
+                Segment::BirthYear => {
+                    verify_birth_year_part(&purified_codice_fiscale[range.clone()]).map(|_| ())
+                }
+                Segment::BirthMonth => {
+                    verify_birth_month_part(&purified_codice_fiscale[range.clone()]).map(|_| ())
+                }
+                Segment::BirthDayAndGender => {
+                    verify_birth_day_and_gender_part(&purified_codice_fiscale[range.clone()])
+                        .map(|_| ())
+                }
+                Segment::BirthPlace => {
+                    verify_birth_place_part(&purified_codice_fiscale[range.clone()])
+                }
+                Segment::ControlCharacter => verify_control_code(codice_fiscale).map(|_| ()),
+            };
+
+            if let Err(error) = outcome {
+                issues.push(error.into());
+            }
+        }
+
+        if !is_reported(&issues, &BIRTH_DATE_PART) {
+            if let (Ok(year), Ok(month), Ok((day, _))) = (
+                verify_birth_year_part(&purified_codice_fiscale[BIRTH_YEAR_PART]),
+                verify_birth_month_part(&purified_codice_fiscale[BIRTH_MONTH_PART]),
+                verify_birth_day_and_gender_part(
+                    &purified_codice_fiscale[BIRTH_DAY_AND_GENDER_PART],
+                ),
+            ) {
+                if let Err(error) = verify_birth_date(year, month, day) {
+                    issues.push(error.into());
+                }
+            }
+        }
+
+        issues.sort_by_key(|issue| issue.span.start);
+        issues
     }
 }
 
-pub fn verify_ascii_alphanumeric(value: &str) -> Result<()> {
-    if let Some(invalid_character_position) = value
-        .as_bytes()
+/// Returns true if one of the issues concerns a character in the range.
+fn is_reported(issues: &[VerificationIssue], range: &Range<usize>) -> bool {
+    issues
         .iter()
-        .position(|c| !c.is_ascii_alphanumeric())
+        .any(|issue| issue.span.start < range.end && range.start < issue.span.end)
+}
+
+pub fn verify_ascii_alphanumeric(value: &str) -> Result<()> {
+    if let Some((position, character)) = value
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
     {
-        return Err(VerifierError::NonAlphanumericCharacter(
-            invalid_character_position,
-        ));
+        return Err(VerifierError::NonAlphanumericCharacter(character, position));
     }
 
     Ok(())
@@ -67,33 +180,46 @@ fn verify_name_part(name_part: &str) -> Result<()> {
     }
 }
 
-fn verify_birth_year_part(birth_year_part: &str) -> Result<()> {
-    let _result: u32 = FromStr::from_str(birth_year_part)
-        .map_err(|_| VerifierError::InvalidBirthYear(birth_year_part.to_string()))?;
-
-    Ok(())
+fn verify_birth_year_part(birth_year_part: &str) -> Result<u32> {
+    FromStr::from_str(birth_year_part)
+        .map_err(|_| VerifierError::InvalidBirthYear(birth_year_part.to_string()))
 }
 
-fn verify_birth_month_part(birth_month_part: &str) -> Result<()> {
-    match birth_month_part.chars().all(|char| is_month_code(&char)) {
-        true => Ok(()),
-        false => Err(VerifierError::InvalidBirthMonth(
-            birth_month_part.to_string(),
-        )),
-    }
+fn verify_birth_month_part(birth_month_part: &str) -> Result<u32> {
+    birth_month_part
+        .chars()
+        .next()
+        .and_then(|char| from_month_code(&char))
+        .ok_or_else(|| VerifierError::InvalidBirthMonth(birth_month_part.to_string()))
 }
 
-fn verify_birth_day_and_gender_part(birth_day_and_gender_part: &str) -> Result<()> {
+fn verify_birth_day_and_gender_part(birth_day_and_gender_part: &str) -> Result<(u32, Gender)> {
     let birth_day: u32 = FromStr::from_str(birth_day_and_gender_part).map_err(|_| {
         VerifierError::InvalidBirthDayAndGender(birth_day_and_gender_part.to_string())
     })?;
-    if (1..=31).contains(&birth_day) || (41..=71).contains(&birth_day) {
-        return Ok(());
+    if (1..=31).contains(&birth_day) {
+        return Ok((birth_day, Gender::M));
+    }
+    if (41..=71).contains(&birth_day) {
+        return Ok((birth_day - 40, Gender::F));
     }
 
     Err(VerifierError::InvalidBirthDayAndGenderRange(birth_day))
 }
 
+/// Checks that the date exists in the calendar either in the 1900s or in the 2000s,
+/// the century is not encoded so February 29 is valid if one of the two years is a leap year.
+fn verify_birth_date(year: u32, month: u32, day: u32) -> Result<()> {
+    let exists = [1900, 2000]
+        .iter()
+        .any(|century| NaiveDate::from_ymd_opt((century + year) as i32, month, day).is_some());
+
+    match exists {
+        true => Ok(()),
+        false => Err(VerifierError::InvalidBirthDate(year, month, day)),
+    }
+}
+
 pub fn verify_birth_place_part(birth_place_part: &str) -> Result<()> {
     match birth_place_part.as_bytes() {
         &[a, b, c, d]
@@ -110,12 +236,52 @@ pub fn verify_birth_place_part(birth_place_part: &str) -> Result<()> {
     }
 }
 
-fn verify_control_code(codice_fiscale: &str) -> Result<()> {
+/// Checks that the birth place is a municipality or a foreign country in the embedded registry.
+#[cfg(feature = "registry")]
+pub fn verify_birth_place_exists(birth_place_part: &str) -> Result<()> {
+    if birth_place_part.starts_with(['Z', 'z']) {
+        return match Country::from_code(birth_place_part) {
+            Some(_) => Ok(()),
+            None => Err(VerifierError::UnknownBirthPlace(
+                birth_place_part.to_string(),
+            )),
+        };
+    }
+
+    match Municipality::from_code(birth_place_part) {
+        Some(_) => Ok(()),
+        None => Err(VerifierError::UnknownBirthPlace(
+            birth_place_part.to_string(),
+        )),
+    }
+}
+
+/// Checks that the birth place is a municipality in the embedded registry that existed on
+/// the birth date. Foreign countries have no validity interval, only their existence is checked.
+#[cfg(feature = "registry")]
+pub fn verify_birth_place_active(birth_place_part: &str, birthdate: NaiveDate) -> Result<()> {
+    if birth_place_part.starts_with(['Z', 'z']) {
+        return verify_birth_place_exists(birth_place_part);
+    }
+
+    match Municipality::from_code(birth_place_part) {
+        Some(municipality) if municipality.is_active_on(birthdate) => Ok(()),
+        Some(_) => Err(VerifierError::InactiveBirthPlace(
+            birth_place_part.to_string(),
+            birthdate,
+        )),
+        None => Err(VerifierError::UnknownBirthPlace(
+            birth_place_part.to_string(),
+        )),
+    }
+}
+
+fn verify_control_code(codice_fiscale: &str) -> Result<char> {
     let expected_control_code = ControlCode::compute(codice_fiscale);
     let control_code = codice_fiscale.chars().last();
 
     match (expected_control_code, control_code) {
-        (expected, Some(value)) if expected == value => Ok(()),
+        (expected, Some(value)) if expected == value => Ok(value),
         (expected, Some(value)) => Err(VerifierError::InvalidControlCharacter(value, expected)),
         (expected, None) => Err(VerifierError::InvalidControlCharacter(' ', expected)),
     }
@@ -139,7 +305,7 @@ mod tests {
     fn invalid_codice_fiscale_not_alphanumeric_character() {
         assert_eq!(
             Verifier::verify("CTmTBT7?E05B506Y"),
-            Err(VerifierError::NonAlphanumericCharacter(7))
+            Err(VerifierError::NonAlphanumericCharacter('?', 7))
         )
     }
 
@@ -179,7 +345,7 @@ mod tests {
     fn invalid_codice_fiscale_birth_year_part() {
         assert_eq!(
             Verifier::verify("CTMTBTy4E05B506W"),
-            Err(VerifierError::InvalidBirthYear("y4".to_string()))
+            Err(VerifierError::InvalidOmocodeCharacter('y', 6))
         )
     }
 
@@ -195,7 +361,7 @@ mod tests {
     fn invalid_codice_fiscale_birth_day_and_gender_part() {
         assert_eq!(
             Verifier::verify("CTMTBT74EF5B506W"),
-            Err(VerifierError::InvalidBirthDayAndGender("F5".to_string()))
+            Err(VerifierError::InvalidOmocodeCharacter('F', 9))
         )
     }
 
@@ -207,11 +373,225 @@ mod tests {
         )
     }
 
+    #[test]
+    fn invalid_codice_fiscale_birth_date() {
+        assert_eq!(
+            Verifier::verify("CTMTBT74D31B506D"),
+            Err(VerifierError::InvalidBirthDate(74, 4, 31))
+        );
+        assert_eq!(
+            Verifier::verify("CTMTBT74B70B506A"),
+            Err(VerifierError::InvalidBirthDate(74, 2, 30))
+        );
+    }
+
+    #[test]
+    fn codice_fiscale_leap_day() {
+        assert_eq!(
+            Verifier::verify("CTMTBT73B29B506A"),
+            Err(VerifierError::InvalidBirthDate(73, 2, 29))
+        );
+        assert!(Verifier::verify("CTMTBT00B29B506D").is_ok());
+        assert!(Verifier::verify("CTMTBT96B69B506H").is_ok());
+    }
+
     #[test]
     fn invalid_codice_fiscale_birth_place_part() {
+        assert_eq!(
+            Verifier::verify("CTMTBT74E311506W"),
+            Err(VerifierError::InvalidBirthPlace("1506".to_string()))
+        )
+    }
+
+    #[test]
+    fn invalid_codice_fiscale_birth_place_omocode_part() {
         assert_eq!(
             Verifier::verify("CTMTBT74E31B5F6W"),
-            Err(VerifierError::InvalidBirthPlace("B5F6".to_string()))
+            Err(VerifierError::InvalidOmocodeCharacter('F', 13))
         )
     }
+
+    #[test]
+    fn valid_codice_fiscale_non_contiguous_omocode() {
+        let decoded = Verifier::decode("RSSMRAT0A01H5L1A").unwrap();
+
+        assert_eq!(decoded.birth_year(), 70);
+        assert_eq!(decoded.birth_place(), "H501");
+        assert_eq!(decoded.omocode_positions(), vec![6, 13]);
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn birth_place_exists() {
+        assert_eq!(verify_birth_place_exists("H501"), Ok(()));
+        assert_eq!(verify_birth_place_exists("Z404"), Ok(()));
+        assert_eq!(
+            verify_birth_place_exists("Z999"),
+            Err(VerifierError::UnknownBirthPlace("Z999".to_string()))
+        );
+        assert_eq!(
+            verify_birth_place_exists("A000"),
+            Err(VerifierError::UnknownBirthPlace("A000".to_string()))
+        );
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn birth_place_active() {
+        let birthdate = NaiveDate::from_ymd_opt(1990, 5, 1).unwrap();
+
+        assert_eq!(verify_birth_place_active("D005", birthdate), Ok(()));
+        assert_eq!(verify_birth_place_active("Z404", birthdate), Ok(()));
+        assert_eq!(
+            verify_birth_place_active("M403", birthdate),
+            Err(VerifierError::InactiveBirthPlace(
+                "M403".to_string(),
+                birthdate
+            ))
+        );
+    }
+
+    #[test]
+    fn verify_all_valid_codice_fiscale() {
+        assert_eq!(Verifier::verify_all("BRNPRZ72D52F83VC"), vec![]);
+    }
+
+    #[test]
+    fn verify_all_invalid_length() {
+        assert_eq!(
+            Verifier::verify_all("CTMTB"),
+            vec![VerificationIssue {
+                error: VerifierError::InvalidLength(5),
+                segment: None,
+                span: 0..5,
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_all_month_and_birth_place() {
+        assert_eq!(
+            Verifier::verify_all("CTMTBT74X05B5F6W"),
+            vec![
+                VerificationIssue {
+                    error: VerifierError::InvalidBirthMonth("X".to_string()),
+                    segment: Some(Segment::BirthMonth),
+                    span: 8..9,
+                },
+                VerificationIssue {
+                    error: VerifierError::InvalidOmocodeCharacter('F', 13),
+                    segment: Some(Segment::BirthPlace),
+                    span: 13..14,
+                },
+                VerificationIssue {
+                    error: VerifierError::InvalidControlCharacter('W', 'R'),
+                    segment: Some(Segment::ControlCharacter),
+                    span: 15..16,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_all_non_alphanumeric_characters() {
+        let issues = Verifier::verify_all("CT?TBT74E05B506-");
+
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.error().clone())
+                .collect::<Vec<VerifierError>>(),
+            vec![
+                VerifierError::NonAlphanumericCharacter('?', 2),
+                VerifierError::NonAlphanumericCharacter('-', 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_all_non_ascii_characters() {
+        let issues = Verifier::verify_all("CTMTBT74E05B50è");
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].span(), 14..16);
+        assert_eq!(issues[0].segment(), Some(Segment::BirthPlace));
+        assert_eq!(
+            Verifier::verify("CTMTBT74E05B50è").unwrap_err().span(),
+            Some(14..16)
+        );
+    }
+
+    #[test]
+    fn verify_all_birth_date() {
+        assert_eq!(
+            Verifier::verify_all("CTMTBT74D31B5F6D"),
+            vec![
+                VerifierError::InvalidBirthDate(74, 4, 31).into(),
+                VerifierError::InvalidOmocodeCharacter('F', 13).into(),
+                VerifierError::InvalidControlCharacter('D', 'P').into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn error_segment_and_span() {
+        assert_eq!(VerifierError::InvalidLength(5).segment(), None);
+        assert_eq!(VerifierError::InvalidLength(5).span(), None);
+        assert_eq!(
+            VerifierError::InvalidName("TB1".to_string()).span(),
+            Some(3..6)
+        );
+        assert_eq!(
+            VerifierError::InvalidBirthDayAndGenderRange(32).segment(),
+            Some(Segment::BirthDayAndGender)
+        );
+        assert_eq!(
+            VerifierError::UnknownBirthPlace("A000".to_string()).span(),
+            Some(11..15)
+        );
+        assert_eq!(
+            VerifierError::InvalidControlCharacter('Y', 'W').span(),
+            Some(15..16)
+        );
+        assert_eq!(
+            VerifierError::NonAlphanumericCharacter('-', 7).segment(),
+            Some(Segment::BirthYear)
+        );
+        assert_eq!(
+            VerifierError::InvalidOmocodeCharacter('F', 13).span(),
+            Some(13..14)
+        );
+        assert_eq!(VerifierError::InvalidBirthDate(74, 4, 31).segment(), None);
+        assert_eq!(
+            VerifierError::InvalidBirthDate(74, 4, 31).span(),
+            Some(BIRTH_DATE_PART)
+        );
+    }
+
+    #[test]
+    fn decode_codice_fiscale() {
+        let decoded = Verifier::decode("cTMTBT74E45B506A").unwrap();
+
+        assert_eq!(decoded.surname(), "CTM");
+        assert_eq!(decoded.name(), "TBT");
+        assert_eq!(decoded.birth_year(), 74);
+        assert_eq!(decoded.birth_month(), 5);
+        assert_eq!(decoded.birth_day(), 5);
+        assert_eq!(decoded.gender(), Gender::F);
+        assert_eq!(decoded.birth_place(), "B506");
+        assert_eq!(decoded.control_character(), 'A');
+    }
+
+    #[test]
+    fn decode_codice_fiscale_omocodo() {
+        let decoded = Verifier::decode("BRNPRZ72D52F83VC").unwrap();
+
+        assert_eq!(decoded.birth_year(), 72);
+        assert_eq!(decoded.birth_month(), 4);
+        assert_eq!(decoded.birth_day(), 12);
+        assert_eq!(decoded.gender(), Gender::F);
+        assert_eq!(decoded.birth_place(), "F839");
+        assert_eq!(decoded.control_character(), 'C');
+        assert_eq!(decoded.omocode_positions(), vec![14]);
+    }
 }
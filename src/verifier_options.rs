@@ -0,0 +1,242 @@
+//! Options changing the rules used to verify a codice fiscale, with two ready made profiles:
+//! a strict one for onboarding and a lenient one for data ingestion. The default options
+//! follow the same rules of [`CodiceFiscale::new`](crate::CodiceFiscale::new).
+
+use crate::{
+    errors::VerifierError,
+    normalization::{normalize, Change},
+    verifier::Verifier,
+};
+
+#[cfg(feature = "registry")]
+use crate::verifier::verify_birth_place_exists;
+
+/// How lowercase letters are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lowercase {
+    /// Verified as they are, as done by `CodiceFiscale::new`.
+    Unchanged,
+    Rejected,
+    /// Converted to uppercase before the verification.
+    Uppercased,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierOptions {
+    lowercase: Lowercase,
+    surrounding_whitespace: bool,
+    inner_whitespace: bool,
+    separators: bool,
+    #[cfg(feature = "registry")]
+    registered_birth_place: bool,
+}
+
+impl Default for VerifierOptions {
+    fn default() -> Self {
+        VerifierOptions {
+            lowercase: Lowercase::Unchanged,
+            surrounding_whitespace: false,
+            inner_whitespace: false,
+            separators: false,
+            #[cfg(feature = "registry")]
+            registered_birth_place: false,
+        }
+    }
+}
+
+impl VerifierOptions {
+    /// Only uppercase letters are accepted. As for every profile, the birth date must exist
+    /// in the calendar.
+    pub fn strict() -> VerifierOptions {
+        VerifierOptions::default().allow_lowercase(false)
+    }
+
+    /// Lowercase letters, converted to uppercase, whitespace around the codice fiscale
+    /// and whitespace or separators between its segments are accepted, following the same
+    /// rules of [`CodiceFiscale::parse`](crate::CodiceFiscale::parse).
+    pub fn lenient() -> VerifierOptions {
+        VerifierOptions::default()
+            .allow_lowercase(true)
+            .allow_surrounding_whitespace(true)
+            .allow_inner_whitespace(true)
+            .allow_separators(true)
+    }
+
+    /// Lowercase ascii letters are converted to uppercase if allowed, rejected otherwise.
+    pub fn allow_lowercase(mut self, allow: bool) -> VerifierOptions {
+        self.lowercase = match allow {
+            true => Lowercase::Uppercased,
+            false => Lowercase::Rejected,
+        };
+        self
+    }
+
+    /// Whitespace before and after the codice fiscale is removed.
+    pub fn allow_surrounding_whitespace(mut self, allow: bool) -> VerifierOptions {
+        self.surrounding_whitespace = allow;
+        self
+    }
+
+    /// Whitespace between the characters of the codice fiscale is removed,
+    /// e.g. `RSS MRA 80A01 H501U`.
+    pub fn allow_inner_whitespace(mut self, allow: bool) -> VerifierOptions {
+        self.inner_whitespace = allow;
+        self
+    }
+
+    /// The separators `-`, `.`, `/` and `_` between the characters of the codice fiscale
+    /// are removed, e.g. `RSS-MRA-80A01-H501U`.
+    pub fn allow_separators(mut self, allow: bool) -> VerifierOptions {
+        self.separators = allow;
+        self
+    }
+
+    /// The birth place must be a municipality or a country in the embedded registry.
+    #[cfg(feature = "registry")]
+    pub fn require_registered_birth_place(mut self, require: bool) -> VerifierOptions {
+        self.registered_birth_place = require;
+        self
+    }
+
+    /// Verifies the codice fiscale following the options and returns it without the
+    /// whitespace and separators that have been accepted, in uppercase if lowercase letters
+    /// are allowed. Positions in the errors refer to the value without those characters.
+    pub(crate) fn verify(&self, raw_codice_fiscale: &str) -> Result<String, VerifierError> {
+        // Byte range of the value without the surrounding whitespace.
+        let start = raw_codice_fiscale.len() - raw_codice_fiscale.trim_start().len();
+        let end = raw_codice_fiscale.trim_end().len();
+
+        // The changes a normalization would do are applied only if allowed by the options.
+        let (_, changes) = normalize(raw_codice_fiscale);
+        let mut changes = changes.into_iter().peekable();
+        let mut codice_fiscale = String::new();
+
+        for (position, character) in raw_codice_fiscale.char_indices() {
+            match changes.next_if(|change| change.position() == position) {
+                Some(Change::Removed { .. }) if character.is_whitespace() => {
+                    let surrounding = position < start || position >= end;
+                    if !(self.inner_whitespace || surrounding && self.surrounding_whitespace) {
+                        codice_fiscale.push(character);
+                    }
+                }
+                Some(Change::Removed { .. }) => {
+                    if !self.separators {
+                        codice_fiscale.push(character);
+                    }
+                }
+                Some(Change::Uppercased { .. }) => match self.lowercase {
+                    Lowercase::Unchanged => codice_fiscale.push(character),
+                    Lowercase::Rejected => {
+                        return Err(VerifierError::LowercaseCharacter(codice_fiscale.len()))
+                    }
+                    Lowercase::Uppercased => codice_fiscale.push(character.to_ascii_uppercase()),
+                },
+                None => codice_fiscale.push(character),
+            }
+        }
+
+        #[cfg(not(feature = "registry"))]
+        Verifier::verify(&codice_fiscale)?;
+
+        #[cfg(feature = "registry")]
+        {
+            let decoded = Verifier::decode(&codice_fiscale)?;
+            if self.registered_birth_place {
+                verify_birth_place_exists(&decoded.birth_place())?;
+            }
+        }
+
+        Ok(codice_fiscale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_options() {
+        let options = VerifierOptions::default();
+
+        assert_eq!(
+            options.verify("cTMTBT74E05B506W"),
+            Ok("cTMTBT74E05B506W".to_string())
+        );
+        assert_eq!(
+            options.verify(" RSSMRA80A01H501U"),
+            Err(VerifierError::InvalidLength(17))
+        );
+    }
+
+    #[test]
+    fn strict_options() {
+        let options = VerifierOptions::strict();
+
+        assert_eq!(
+            options.verify("RSSMRA80A01H501U"),
+            Ok("RSSMRA80A01H501U".to_string())
+        );
+        assert_eq!(
+            options.verify("RSSMRa80A01H501U"),
+            Err(VerifierError::LowercaseCharacter(5))
+        );
+        assert_eq!(
+            options.verify("RSSMé80A01H501U"),
+            Err(VerifierError::NonAlphanumericCharacter('é', 4))
+        );
+        assert!(options.verify("BLTMHL77S04E889G").is_ok());
+        assert!(options.verify("ZLKESP25B55Y463L").is_ok());
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn registered_birth_place() {
+        let options = VerifierOptions::strict().require_registered_birth_place(true);
+
+        assert_eq!(
+            options.verify("RSSMRA80A01A000C"),
+            Err(VerifierError::UnknownBirthPlace("A000".to_string()))
+        );
+        assert!(VerifierOptions::strict().verify("RSSMRA80A01A000C").is_ok());
+    }
+
+    #[test]
+    fn lenient_options() {
+        let options = VerifierOptions::lenient();
+
+        assert_eq!(
+            options.verify(" rss mra 80a01 h501u\n"),
+            Ok("RSSMRA80A01H501U".to_string())
+        );
+        assert_eq!(
+            options.verify("RSS-MRA-80A01-H501U"),
+            Ok("RSSMRA80A01H501U".to_string())
+        );
+    }
+
+    #[test]
+    fn whitespace_without_separators() {
+        let options = VerifierOptions::default()
+            .allow_surrounding_whitespace(true)
+            .allow_inner_whitespace(true);
+
+        assert_eq!(
+            options.verify("RSS-MRA-80A01-H501U"),
+            Err(VerifierError::InvalidLength(19))
+        );
+    }
+
+    #[test]
+    fn surrounding_whitespace_only() {
+        let options = VerifierOptions::default().allow_surrounding_whitespace(true);
+
+        assert_eq!(
+            options.verify("\tRSSMRA80A01H501U "),
+            Ok("RSSMRA80A01H501U".to_string())
+        );
+        assert_eq!(
+            options.verify("RSS MRA80A01H501U"),
+            Err(VerifierError::InvalidLength(17))
+        );
+    }
+}
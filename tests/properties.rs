@@ -0,0 +1,290 @@
+//! Property tests feeding arbitrary input to the public API: no function should panic,
+//! invalid input must always be reported with an error.
+
+use chrono::{Datelike, NaiveDate};
+use codice_fiscale_rs::{
+    century_policy::CenturyPolicy,
+    clock::FixedClock,
+    person_data::{Gender, PersonData},
+    plausibility::Plausibility,
+    transliteration::TransliterationTable,
+    verifier_options::VerifierOptions,
+    CodiceFiscale,
+};
+use proptest::prelude::*;
+
+fn any_date() -> impl Strategy<Value = NaiveDate> {
+    (NaiveDate::MIN.num_days_from_ce()..=NaiveDate::MAX.num_days_from_ce())
+        .prop_filter_map("out of range", NaiveDate::from_num_days_from_ce_opt)
+}
+
+fn any_gender() -> impl Strategy<Value = Gender> {
+    prop_oneof![Just(Gender::M), Just(Gender::F)]
+}
+
+/// Strings shaped like a name, mixed with arbitrary ones.
+fn name_like() -> impl Strategy<Value = String> {
+    prop_oneof!["[A-Za-z0-9àèìòù' -]{0,12}", "\\PC{0,20}"]
+}
+
+/// Strings shaped like a codice fiscale, so that the checks after the length one are reached.
+fn codice_fiscale_like() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[A-Za-z0-9]{16}",
+        "[A-Z]{6}[0-9LMNPQRSTUV]{2}[ABCDEHLMPRST][0-9LMNPQRSTUV]{2}[A-Z][0-9LMNPQRSTUV]{3}[A-Z]",
+        "\\PC{16}",
+        ".{0,20}",
+    ]
+}
+
+fn valid_codice_fiscale() -> impl Strategy<Value = CodiceFiscale> {
+    (
+        "[A-Z]{0,10}",
+        "[A-Z]{0,10}",
+        any_date(),
+        any_gender(),
+        "[A-Z][0-9]{3}",
+    )
+        .prop_map(|(name, surname, birthdate, gender, place_of_birth)| {
+            let person_data =
+                PersonData::new(name, surname, birthdate, gender, place_of_birth).unwrap();
+            CodiceFiscale::generate(&person_data)
+        })
+}
+
+proptest! {
+    #[test]
+    fn new_never_panics(value in "\\PC*") {
+        let _ = CodiceFiscale::new(&value);
+    }
+
+    #[test]
+    fn verify_all_agrees_with_new(value in codice_fiscale_like()) {
+        match (CodiceFiscale::new(&value), CodiceFiscale::verify_all(&value)) {
+            (Ok(codice_fiscale), Ok(verified)) => prop_assert_eq!(codice_fiscale, verified),
+            (Err(error), Err(issues)) => {
+                prop_assert!(issues.iter().any(|issue| issue.error() == &error));
+                prop_assert!(error.span().is_none_or(|span| span.end <= 16));
+                prop_assert!(issues.iter().all(|issue| issue.span().end <= value.len()));
+            }
+            (outcome, issues) => prop_assert!(false, "{:?} {:?}", outcome, issues),
+        }
+    }
+
+    #[test]
+    fn new_never_panics_on_codice_fiscale_like_input(value in codice_fiscale_like()) {
+        if let Ok(codice_fiscale) = CodiceFiscale::new(&value) {
+            prop_assert!(codice_fiscale.decode().is_ok());
+            let _ = codice_fiscale.omocode_positions();
+            let _ = codice_fiscale.is_omocode();
+            let _ = codice_fiscale.omocodes();
+            let _ = codice_fiscale.all_omocodes();
+            let _ = codice_fiscale.canonical();
+        }
+    }
+
+    #[test]
+    fn default_options_agree_with_new(value in codice_fiscale_like()) {
+        prop_assert_eq!(
+            CodiceFiscale::new_with_options(&value, &VerifierOptions::default()),
+            CodiceFiscale::new(&value)
+        );
+    }
+
+    #[test]
+    fn lenient_options_accept_formatted_codes(codice_fiscale in valid_codice_fiscale()) {
+        let value = codice_fiscale.get();
+        let formatted = format!(" {} {} {} ", &value[..3], &value[3..6], value[6..].to_lowercase());
+
+        prop_assert_eq!(
+            CodiceFiscale::new_with_options(&formatted, &VerifierOptions::lenient()),
+            Ok(codice_fiscale)
+        );
+    }
+
+    #[test]
+    fn lenient_options_agree_with_parse(value in codice_fiscale_like(), separator in "[ .\\-/_]{0,2}") {
+        let formatted = format!("{separator}{value}{separator}");
+
+        prop_assert_eq!(
+            CodiceFiscale::new_with_options(&formatted, &VerifierOptions::lenient()),
+            CodiceFiscale::parse(&formatted).map(CodiceFiscale::from)
+        );
+    }
+
+    #[test]
+    fn parse_normalizes_formatted_codes(
+        codice_fiscale in valid_codice_fiscale(),
+        separator in "[ .\\-/_]{0,2}",
+    ) {
+        let value = codice_fiscale.get();
+        let formatted = [&value[..3], &value[3..6], &value[6..11], &value[11..]]
+            .join(&separator)
+            .to_lowercase();
+
+        let normalized = CodiceFiscale::parse(&formatted).unwrap();
+        prop_assert_eq!(normalized.codice_fiscale(), &codice_fiscale);
+    }
+
+    #[test]
+    fn stored_codes_are_uppercase(value in codice_fiscale_like()) {
+        if let Ok(codice_fiscale) = CodiceFiscale::new(&value) {
+            prop_assert_eq!(codice_fiscale.get(), value.to_uppercase());
+        }
+    }
+
+    #[test]
+    fn omocodes_are_valid(codice_fiscale in valid_codice_fiscale(), level in 0usize..16) {
+        for omocode in codice_fiscale.all_omocodes() {
+            prop_assert!(CodiceFiscale::new(&omocode.get()).is_ok());
+            prop_assert!(omocode.is_omocode_of(&codice_fiscale));
+        }
+
+        match codice_fiscale.omocode(level) {
+            Ok(omocode) => prop_assert_eq!(omocode.omocode_level(), level),
+            Err(_) => prop_assert!(level > 7),
+        }
+    }
+
+    #[test]
+    fn suggestions_recover_a_single_typo(
+        codice_fiscale in valid_codice_fiscale(),
+        position in 0usize..16,
+        replacement in "[A-Z0-9]",
+    ) {
+        let mut typo: Vec<char> = codice_fiscale.get().chars().collect();
+        typo[position] = replacement.chars().next().unwrap();
+        let typo: String = typo.iter().collect();
+
+        let suggestions = CodiceFiscale::suggestions(&typo);
+        if CodiceFiscale::new(&typo).is_err() {
+            prop_assert!(suggestions
+                .iter()
+                .any(|suggestion| suggestion.codice_fiscale() == &codice_fiscale));
+        } else {
+            prop_assert!(suggestions.is_empty());
+        }
+    }
+
+    #[test]
+    fn completion_recovers_illegible_characters(
+        codice_fiscale in valid_codice_fiscale(),
+        positions in prop::collection::vec(0usize..16, 1..=2),
+    ) {
+        let mut illegible: Vec<char> = codice_fiscale.get().chars().collect();
+        for position in positions {
+            illegible[position] = '?';
+        }
+        let illegible: String = illegible.iter().collect();
+
+        let completions = CodiceFiscale::complete(&illegible).unwrap();
+        prop_assert!(completions.contains(&codice_fiscale));
+    }
+
+    #[test]
+    fn completion_never_panics(value in "[A-Z0-9?]{16}|\\PC{0,20}") {
+        let _ = CodiceFiscale::complete(&value);
+    }
+
+    #[test]
+    fn generate_never_panics(
+        name in name_like(),
+        surname in name_like(),
+        birthdate in any_date(),
+        gender in any_gender(),
+        place_of_birth in "\\PC{0,5}",
+        level in 0usize..16,
+        positions in prop::collection::vec(0usize..20, 0..8),
+    ) {
+        if let Ok(person_data) = PersonData::new(name, surname, birthdate, gender, place_of_birth) {
+            let codice_fiscale = CodiceFiscale::generate(&person_data);
+            prop_assert!(CodiceFiscale::new(&codice_fiscale.get()).is_ok());
+            prop_assert!(codice_fiscale.matches(&person_data).is_match());
+
+            let _ = CodiceFiscale::generate_omocode(&person_data, level);
+            if let Ok(omocode) = CodiceFiscale::generate_omocode_at(&person_data, &positions) {
+                prop_assert!(CodiceFiscale::new(&omocode.get()).is_ok());
+                prop_assert!(omocode.matches(&person_data).is_match());
+            }
+        }
+    }
+
+    #[test]
+    fn generate_transliterated_never_panics(
+        name in "\\PC{0,20}",
+        surname in "\\PC{0,20}",
+        birthdate in any_date(),
+        gender in any_gender(),
+    ) {
+        let _ = CodiceFiscale::generate_transliterated(
+            name,
+            surname,
+            birthdate,
+            gender,
+            "Z112".to_string(),
+            &TransliterationTable::default(),
+        );
+    }
+
+    #[test]
+    fn century_policy_never_panics(
+        year in any::<u32>(),
+        month in any::<u32>(),
+        day in any::<u32>(),
+        pivot in any::<u32>(),
+        reference in any_date(),
+        max_age in any::<u32>(),
+    ) {
+        let _ = CenturyPolicy::Pivot(pivot).candidates(year, month, day);
+        let _ = CenturyPolicy::RelativeTo(reference).candidates(year, month, day);
+        let _ = CenturyPolicy::MaxAge { reference, max_age }.candidates(year, month, day);
+    }
+
+    #[test]
+    fn plausible_birth_dates_are_not_in_the_future(
+        codice_fiscale in valid_codice_fiscale(),
+        today in any_date(),
+        max_age in any::<u32>(),
+        pivot in proptest::option::of(any::<u32>()),
+    ) {
+        let mut plausibility = Plausibility::new(max_age);
+        if let Some(pivot) = pivot {
+            plausibility = plausibility.with_century_policy(CenturyPolicy::Pivot(pivot));
+        }
+
+        if let Ok(birthdate) = codice_fiscale.verify_plausibility(&plausibility, &FixedClock(today)) {
+            prop_assert!(birthdate <= today);
+        }
+    }
+
+    #[test]
+    fn decoded_birthdate_never_panics(codice_fiscale in valid_codice_fiscale(), reference in any_date()) {
+        let decoded = codice_fiscale.decode().unwrap();
+
+        let _ = decoded.birthdate(&CenturyPolicy::RelativeTo(reference));
+        let _ = decoded.birthdate_candidates(&CenturyPolicy::MaxAge { reference, max_age: 120 });
+    }
+}
+
+#[cfg(feature = "registry")]
+mod registry {
+    use codice_fiscale_rs::{countries::Country, municipalities::Municipality};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn registry_lookups_never_panic(value in "\\PC{0,20}", province in "\\PC{0,3}") {
+            let _ = Municipality::from_code(&value);
+            let _ = Municipality::search(&value, Some(&province));
+            let _ = Country::from_code(&value);
+            let _ = Country::from_iso(&value);
+        }
+
+        #[test]
+        fn verify_birth_place_never_panics(codice_fiscale in valid_codice_fiscale(), pivot in any::<u32>()) {
+            let _ = codice_fiscale.verify_birth_place();
+            let _ = codice_fiscale.verify_birth_place_on_birthdate(&CenturyPolicy::Pivot(pivot));
+        }
+    }
+}